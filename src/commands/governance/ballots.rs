@@ -0,0 +1,72 @@
+use crate::lib::client::governance::GovernanceClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::enum_name;
+
+use anyhow::anyhow;
+use clap::Clap;
+use ic_nns_governance::pb::v1::{Ballot, Vote};
+use ledger_canister::ICPTs;
+use std::collections::BTreeMap;
+
+/// Show the ballots recorded for a proposal with the vote and voting power of each neuron
+#[derive(Clap)]
+pub struct BallotsOpts {
+    /// Proposal id
+    id: u64,
+
+    /// Only show the ballots of these neurons
+    #[clap(long)]
+    neuron: Vec<u64>,
+}
+
+pub async fn exec(opts: BallotsOpts, env: Env) -> NnsCliResult {
+    let info = GovernanceClient::new(&env)
+        .get_proposal_info(opts.id)
+        .await?
+        .ok_or_else(|| anyhow!("No proposal found with id {}", opts.id))?;
+
+    let ballots: BTreeMap<u64, Ballot> = info
+        .ballots
+        .into_iter()
+        .filter(|(id, _)| opts.neuron.is_empty() || opts.neuron.contains(id))
+        .collect();
+
+    // Ballots and voting power per vote.
+    let mut totals: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+    println!("{:<24}{:<16}{:>24}", "Neuron", "Vote", "Voting power");
+    for (id, ballot) in &ballots {
+        let vote = enum_name(Vote::from_i32(ballot.vote), ballot.vote);
+        println!(
+            "{:<24}{:<16}{:>24}",
+            id,
+            vote,
+            ICPTs::from_e8s(ballot.voting_power).to_string()
+        );
+        let total = totals.entry(vote).or_default();
+        total.0 += 1;
+        total.1 += ballot.voting_power;
+    }
+
+    println!();
+    for (vote, (count, voting_power)) in &totals {
+        println!(
+            "{:<24}{:<16}{:>24}",
+            vote,
+            format!("{} ballots", count),
+            ICPTs::from_e8s(*voting_power).to_string()
+        );
+    }
+    println!(
+        "{:<24}{:<16}{:>24}",
+        "Total",
+        format!("{} ballots", ballots.len()),
+        ICPTs::from_e8s(ballots.values().map(|b| b.voting_power).sum()).to_string()
+    );
+
+    for id in opts.neuron.iter().filter(|id| !ballots.contains_key(id)) {
+        eprintln!("Neuron {} has no ballot on proposal {}.", id, opts.id);
+    }
+
+    NnsCliResult::Ok(())
+}
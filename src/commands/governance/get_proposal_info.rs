@@ -1,12 +1,17 @@
+use crate::lib::client::governance::GovernanceClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
-use crate::lib::nns_types::governance::governance_canister_id;
+use crate::lib::nns_types::governance::{enum_name, format_action, format_tally};
+use crate::lib::nns_types::utils::format_timestamp;
 
-use candid::{Decode, Encode};
+use anyhow::anyhow;
 use clap::Clap;
-use ic_nns_governance::pb::v1::ProposalInfo;
+use ic_nns_governance::pb::v1::{ProposalInfo, ProposalRewardStatus, ProposalStatus, Topic};
 
-const GET_PROPOSAL_INFO_METHOD: &str = "get_proposal_info";
+// The time proposals are open for voting, unless decided earlier by an absolute
+// majority. The deadline derived from it is only an estimate, as the governance
+// canister may extend it.
+const VOTING_PERIOD_SECONDS: u64 = 4 * 24 * 3600;
 
 /// Call governance canister's get_proposal_info method
 #[derive(Clap)]
@@ -15,20 +20,71 @@ pub struct GetProposalInfoOpts {
     id: u64,
 }
 
-pub async fn exec(opts: GetProposalInfoOpts, env: Env) -> NnsCliResult {
-    let result = env
-        .agent
-        .query(&governance_canister_id(), GET_PROPOSAL_INFO_METHOD)
-        .with_arg(Encode!(&(opts.id))?)
-        .call()
-        .await?;
-
-    let maybe_proposal = Decode!(&result, Option<ProposalInfo>)?;
-
-    match maybe_proposal {
-        Some(proposal) => println!("{:?}", proposal),
-        None => println!("No proposal found with id {}", opts.id),
+fn print_proposal_info(info: &ProposalInfo) {
+    let print_timestamp = |label: &str, seconds: u64| {
+        if seconds > 0 {
+            println!("{:<16}{}", label, format_timestamp(seconds));
+        }
     };
 
+    if let Some(id) = &info.id {
+        println!("{:<16}{}", "Proposal:", id.id);
+    }
+    if let Some(proposer) = &info.proposer {
+        println!("{:<16}{}", "Proposer:", proposer.id);
+    }
+    println!(
+        "{:<16}{}",
+        "Topic:",
+        enum_name(Topic::from_i32(info.topic), info.topic)
+    );
+    println!(
+        "{:<16}{}",
+        "Status:",
+        enum_name(ProposalStatus::from_i32(info.status), info.status)
+    );
+    println!(
+        "{:<16}{}",
+        "Reward status:",
+        enum_name(
+            ProposalRewardStatus::from_i32(info.reward_status),
+            info.reward_status
+        )
+    );
+    print_timestamp("Created:", info.proposal_timestamp_seconds);
+    if info.status == ProposalStatus::Open as i32 {
+        println!(
+            "{:<16}{} (estimated)",
+            "Deadline:",
+            format_timestamp(info.proposal_timestamp_seconds + VOTING_PERIOD_SECONDS)
+        );
+    }
+    print_timestamp("Decided:", info.decided_timestamp_seconds);
+    print_timestamp("Executed:", info.executed_timestamp_seconds);
+    print_timestamp("Failed:", info.failed_timestamp_seconds);
+    if let Some(failure_reason) = &info.failure_reason {
+        println!("{:<16}{}", "Failure reason:", failure_reason);
+    }
+    if let Some(tally) = &info.latest_tally {
+        println!("{:<16}{}", "Tally:", format_tally(tally));
+    }
+
+    if let Some(proposal) = &info.proposal {
+        println!("{:<16}{}", "Url:", proposal.url);
+        println!("\nSummary:\n{}", proposal.summary.trim_end());
+        if let Some(action) = &proposal.action {
+            println!("\nAction: {}", format_action(action));
+        }
+    }
+}
+
+pub async fn exec(opts: GetProposalInfoOpts, env: Env) -> NnsCliResult {
+    let info = GovernanceClient::new(&env)
+        .get_proposal_info(opts.id)
+        .await?
+        .ok_or_else(|| anyhow!("No proposal found with id {}", opts.id))?;
+
+    print_proposal_info(&info);
+
     NnsCliResult::Ok(())
 }
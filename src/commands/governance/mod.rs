@@ -3,8 +3,10 @@ use crate::lib::error::NnsCliResult;
 
 use clap::Clap;
 
+mod ballots;
 mod get_proposal_info;
 mod submit_proposal;
+mod watch_proposal;
 
 /// Call the governance canister
 #[derive(Clap)]
@@ -16,13 +18,17 @@ pub struct GovernanceOpts {
 
 #[derive(Clap)]
 enum SubCommand {
+    Ballots(ballots::BallotsOpts),
     GetProposalInfo(get_proposal_info::GetProposalInfoOpts),
     SubmitProposal(submit_proposal::SubmitProposalOpts),
+    WatchProposal(watch_proposal::WatchProposalOpts),
 }
 
 pub async fn exec(opts: GovernanceOpts, env: Env) -> NnsCliResult {
     match opts.subcmd {
+        SubCommand::Ballots(v) => ballots::exec(v, env).await,
         SubCommand::GetProposalInfo(v) => get_proposal_info::exec(v, env).await,
         SubCommand::SubmitProposal(v) => submit_proposal::exec(v, env).await,
+        SubCommand::WatchProposal(v) => watch_proposal::exec(v, env).await,
     }
 }
@@ -0,0 +1,38 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::AddNodeOperatorPayload;
+
+use candid::Encode;
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::AssignNoid;
+use ic_types::Principal;
+
+/// Submit a proposal to add a node operator, assigning it to a node provider
+#[derive(Clap, Clone)]
+pub struct AddNodeOperatorOpts {
+    /// The principal of the node operator.
+    #[clap(long)]
+    node_operator: Principal,
+
+    /// The principal of the node provider the node operator works for.
+    #[clap(long)]
+    node_provider: Principal,
+
+    /// The number of nodes the node operator may add to the registry.
+    #[clap(long)]
+    node_allowance: u64,
+}
+
+pub async fn exec(
+    opts: AddNodeOperatorOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload = Encode!(&AddNodeOperatorPayload {
+        node_operator_principal_id: Some(opts.node_operator),
+        node_allowance: opts.node_allowance,
+        node_provider_principal_id: Some(opts.node_provider),
+    })?;
+
+    super::submit_nns_function(AssignNoid, payload, proposal_opts, env).await
+}
@@ -0,0 +1,33 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::AddNodesToSubnetPayload;
+
+use candid::Encode;
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::AddNodeToSubnet;
+use ic_types::Principal;
+
+/// Submit a proposal to add unassigned nodes to a subnet
+#[derive(Clap, Clone)]
+pub struct AddNodesToSubnetOpts {
+    /// The subnet to add the nodes to.
+    #[clap(long)]
+    subnet: Principal,
+
+    /// The ids of the nodes to add.
+    #[clap(long, required = true)]
+    node_ids: Vec<Principal>,
+}
+
+pub async fn exec(
+    opts: AddNodesToSubnetOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload = Encode!(&AddNodesToSubnetPayload {
+        subnet_id: opts.subnet,
+        node_ids: opts.node_ids,
+    })?;
+
+    super::submit_nns_function(AddNodeToSubnet, payload, proposal_opts, env).await
+}
@@ -0,0 +1,43 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+
+use anyhow::anyhow;
+use clap::Clap;
+use ic_base_types::PrincipalId;
+use ic_nns_governance::pb::v1::add_or_remove_node_provider::Change;
+use ic_nns_governance::pb::v1::proposal::Action;
+use ic_nns_governance::pb::v1::{AddOrRemoveNodeProvider, NodeProvider};
+use ic_types::Principal;
+use std::convert::TryFrom;
+
+/// Submit a proposal to add or remove a node provider
+#[derive(Clap, Clone)]
+pub struct AddOrRemoveNodeProviderOpts {
+    /// The principal of the node provider.
+    #[clap(long)]
+    node_provider: Principal,
+
+    /// Remove the node provider instead of adding it.
+    #[clap(long)]
+    remove: bool,
+}
+
+pub async fn exec(
+    opts: AddOrRemoveNodeProviderOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let node_provider = NodeProvider {
+        id: Some(PrincipalId::try_from(opts.node_provider.as_slice()).map_err(|err| anyhow!(err))?),
+    };
+    let change = if opts.remove {
+        Change::ToRemove(node_provider)
+    } else {
+        Change::ToAdd(node_provider)
+    };
+    let action = Action::AddOrRemoveNodeProvider(AddOrRemoveNodeProvider {
+        change: Some(change),
+    });
+
+    super::submit(action, proposal_opts, env).await
+}
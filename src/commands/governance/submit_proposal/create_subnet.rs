@@ -0,0 +1,107 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::{CreateSubnetPayload, SubnetType};
+
+use candid::Encode;
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::CreateSubnet;
+use ic_types::Principal;
+
+// The gossip configuration every subnet is created with.
+const GOSSIP_MAX_ARTIFACT_STREAMS_PER_PEER: u32 = 20;
+const GOSSIP_MAX_CHUNK_WAIT_MS: u32 = 15_000;
+const GOSSIP_MAX_DUPLICITY: u32 = 1;
+const GOSSIP_MAX_CHUNK_SIZE: u32 = 4096;
+const GOSSIP_RECEIVE_CHECK_CACHE_SIZE: u32 = 5000;
+const GOSSIP_PFN_EVALUATION_PERIOD_MS: u32 = 3000;
+const GOSSIP_REGISTRY_POLL_PERIOD_MS: u32 = 3000;
+const GOSSIP_RETRANSMISSION_REQUEST_MS: u32 = 60_000;
+
+/// Submit a proposal to create a new subnet from unassigned nodes
+#[derive(Clap, Clone)]
+pub struct CreateSubnetOpts {
+    /// The ids of the nodes that will form the subnet.
+    #[clap(long, required = true)]
+    node_ids: Vec<Principal>,
+
+    /// The replica version the subnet will run.
+    #[clap(long)]
+    replica_version_id: String,
+
+    /// The type of the subnet, which determines who may install canisters on it.
+    #[clap(long, possible_values = &["application", "system", "verified-application"], default_value = "application")]
+    subnet_type: SubnetType,
+
+    /// Use this id for the subnet instead of deriving it, for testing only.
+    #[clap(long)]
+    subnet_id_override: Option<Principal>,
+
+    /// The soft cap on the size of the ingress messages in a block, in bytes.
+    #[clap(long, default_value = "2097152")]
+    ingress_bytes_per_block_soft_cap: u64,
+
+    /// The maximum size of an ingress message, in bytes.
+    #[clap(long, default_value = "2097152")]
+    max_ingress_bytes_per_message: u64,
+
+    /// The maximum number of ingress messages in a block.
+    #[clap(long, default_value = "1000")]
+    max_ingress_messages_per_block: u64,
+
+    /// The maximum size of a block payload, in bytes.
+    #[clap(long, default_value = "4194304")]
+    max_block_payload_size: u64,
+
+    /// The unit delay of the consensus protocol, in milliseconds.
+    #[clap(long, default_value = "1000")]
+    unit_delay_millis: u64,
+
+    /// The delay before notarizing a block, in milliseconds.
+    #[clap(long, default_value = "600")]
+    initial_notary_delay_millis: u64,
+
+    /// The number of blocks between distributed key generations.
+    #[clap(long, default_value = "499")]
+    dkg_interval_length: u64,
+
+    /// The maximum number of distributed key generation dealings in a block.
+    #[clap(long, default_value = "1")]
+    dkg_dealings_per_block: u64,
+
+    /// Start the subnet as the NNS subnet.
+    #[clap(long)]
+    start_as_nns: bool,
+}
+
+pub async fn exec(
+    opts: CreateSubnetOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload = Encode!(&CreateSubnetPayload {
+        node_ids: opts.node_ids,
+        subnet_id_override: opts.subnet_id_override,
+        ingress_bytes_per_block_soft_cap: opts.ingress_bytes_per_block_soft_cap,
+        max_ingress_bytes_per_message: opts.max_ingress_bytes_per_message,
+        max_ingress_messages_per_block: opts.max_ingress_messages_per_block,
+        max_block_payload_size: opts.max_block_payload_size,
+        unit_delay_millis: opts.unit_delay_millis,
+        initial_notary_delay_millis: opts.initial_notary_delay_millis,
+        replica_version_id: opts.replica_version_id,
+        dkg_interval_length: opts.dkg_interval_length,
+        dkg_dealings_per_block: opts.dkg_dealings_per_block,
+        gossip_max_artifact_streams_per_peer: GOSSIP_MAX_ARTIFACT_STREAMS_PER_PEER,
+        gossip_max_chunk_wait_ms: GOSSIP_MAX_CHUNK_WAIT_MS,
+        gossip_max_duplicity: GOSSIP_MAX_DUPLICITY,
+        gossip_max_chunk_size: GOSSIP_MAX_CHUNK_SIZE,
+        gossip_receive_check_cache_size: GOSSIP_RECEIVE_CHECK_CACHE_SIZE,
+        gossip_pfn_evaluation_period_ms: GOSSIP_PFN_EVALUATION_PERIOD_MS,
+        gossip_registry_poll_period_ms: GOSSIP_REGISTRY_POLL_PERIOD_MS,
+        gossip_retransmission_request_ms: GOSSIP_RETRANSMISSION_REQUEST_MS,
+        start_as_nns: opts.start_as_nns,
+        subnet_type: opts.subnet_type,
+        is_halted: false,
+    })?;
+
+    super::submit_nns_function(CreateSubnet, payload, proposal_opts, env).await
+}
@@ -0,0 +1,47 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::{nns_function_from_str, nns_function_validator};
+
+use anyhow::bail;
+use candid::IDLArgs;
+use clap::Clap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Submit a proposal to execute any NNS function with a Candid payload
+#[derive(Clap, Clone)]
+pub struct ExecuteNnsFunctionOpts {
+    /// The NNS function to execute, by name (e.g. `SetAuthorizedSubnetworks`) or number.
+    #[clap(long, validator(nns_function_validator))]
+    function: String,
+
+    /// The payload as Candid text, i.e. `(record { who = null; subnets = vec {} })`.
+    /// Numbers default to `int`, so annotate them with their type, i.e. `(42 : nat64)`.
+    #[clap(long, conflicts_with("payload-file"))]
+    payload_candid: Option<String>,
+
+    /// A file containing the payload as Candid text.
+    #[clap(long)]
+    payload_file: Option<PathBuf>,
+}
+
+pub async fn exec(
+    opts: ExecuteNnsFunctionOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload_candid = match (opts.payload_candid, opts.payload_file) {
+        (Some(text), None) => text,
+        (None, Some(path)) => tokio::fs::read_to_string(path).await?,
+        _ => bail!("Please specify the payload with either --payload-candid or --payload-file"),
+    };
+    let payload = IDLArgs::from_str(&payload_candid)?.to_bytes()?;
+
+    super::submit_nns_function(
+        nns_function_from_str(&opts.function)?,
+        payload,
+        proposal_opts,
+        env,
+    )
+    .await
+}
@@ -0,0 +1,144 @@
+use crate::lib::client::governance::GovernanceClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::utils::{icpts_amount_validator, icpts_from_str};
+
+use clap::Clap;
+use ic_nns_governance::pb::v1::proposal::Action;
+use ic_nns_governance::pb::v1::NetworkEconomics;
+use ledger_canister::ICPTs;
+
+/// Submit a proposal to change the network economics.
+/// Parameters that are not specified keep their current value.
+#[derive(Clap, Clone)]
+pub struct ManageNetworkEconomicsOpts {
+    /// ICP charged for a proposal that gets rejected.
+    #[clap(long, validator(icpts_amount_validator))]
+    reject_cost: Option<String>,
+
+    /// The minimum ICP a neuron can be staked with.
+    #[clap(long, validator(icpts_amount_validator))]
+    neuron_minimum_stake: Option<String>,
+
+    /// ICP charged for each proposal that manages a neuron.
+    #[clap(long, validator(icpts_amount_validator))]
+    neuron_management_fee_per_proposal: Option<String>,
+
+    /// The minimum ICP/XDR rate, in XDR permyriad per ICP.
+    #[clap(long)]
+    minimum_icp_xdr_rate: Option<u64>,
+
+    /// The dissolve delay of neurons spawned from maturity, in seconds.
+    #[clap(long)]
+    neuron_spawn_dissolve_delay_seconds: Option<u64>,
+
+    /// The maximum ICP a node provider can be rewarded with in one proposal.
+    #[clap(long, validator(icpts_amount_validator))]
+    maximum_node_provider_rewards: Option<String>,
+
+    /// The ledger transaction fee.
+    #[clap(long, validator(icpts_amount_validator))]
+    transaction_fee: Option<String>,
+
+    /// The number of decided proposals to keep per topic.
+    #[clap(long)]
+    max_proposals_to_keep_per_topic: Option<u32>,
+}
+
+fn override_e8s(value: &mut u64, icpts: Option<String>) -> NnsCliResult {
+    if let Some(icpts) = icpts {
+        *value = icpts_from_str(&icpts)?.get_e8s();
+    }
+    Ok(())
+}
+
+fn print_comparison(current: &NetworkEconomics, proposed: &NetworkEconomics) {
+    let icpts = |e8s: u64| format!("{} ICP", ICPTs::from_e8s(e8s));
+    let rows = vec![
+        (
+            "Reject cost",
+            icpts(current.reject_cost_e8s),
+            icpts(proposed.reject_cost_e8s),
+        ),
+        (
+            "Neuron minimum stake",
+            icpts(current.neuron_minimum_stake_e8s),
+            icpts(proposed.neuron_minimum_stake_e8s),
+        ),
+        (
+            "Neuron management fee per proposal",
+            icpts(current.neuron_management_fee_per_proposal_e8s),
+            icpts(proposed.neuron_management_fee_per_proposal_e8s),
+        ),
+        (
+            "Minimum ICP/XDR rate (permyriad)",
+            current.minimum_icp_xdr_rate.to_string(),
+            proposed.minimum_icp_xdr_rate.to_string(),
+        ),
+        (
+            "Neuron spawn dissolve delay (seconds)",
+            current.neuron_spawn_dissolve_delay_seconds.to_string(),
+            proposed.neuron_spawn_dissolve_delay_seconds.to_string(),
+        ),
+        (
+            "Maximum node provider rewards",
+            icpts(current.maximum_node_provider_rewards_e8s),
+            icpts(proposed.maximum_node_provider_rewards_e8s),
+        ),
+        (
+            "Transaction fee",
+            icpts(current.transaction_fee_e8s),
+            icpts(proposed.transaction_fee_e8s),
+        ),
+        (
+            "Max proposals to keep per topic",
+            current.max_proposals_to_keep_per_topic.to_string(),
+            proposed.max_proposals_to_keep_per_topic.to_string(),
+        ),
+    ];
+
+    println!("{:<40}{:>24}{:>24}", "Parameter", "Current", "Proposed");
+    for (name, current, proposed) in rows {
+        let marker = if current == proposed { "" } else { " *" };
+        println!("{:<40}{:>24}{:>24}{}", name, current, proposed, marker);
+    }
+}
+
+pub async fn exec(
+    opts: ManageNetworkEconomicsOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let current = GovernanceClient::new(&env)
+        .get_network_economics_parameters()
+        .await?;
+
+    let mut proposed = current.clone();
+    override_e8s(&mut proposed.reject_cost_e8s, opts.reject_cost)?;
+    override_e8s(
+        &mut proposed.neuron_minimum_stake_e8s,
+        opts.neuron_minimum_stake,
+    )?;
+    override_e8s(
+        &mut proposed.neuron_management_fee_per_proposal_e8s,
+        opts.neuron_management_fee_per_proposal,
+    )?;
+    override_e8s(
+        &mut proposed.maximum_node_provider_rewards_e8s,
+        opts.maximum_node_provider_rewards,
+    )?;
+    override_e8s(&mut proposed.transaction_fee_e8s, opts.transaction_fee)?;
+    if let Some(v) = opts.minimum_icp_xdr_rate {
+        proposed.minimum_icp_xdr_rate = v;
+    }
+    if let Some(v) = opts.neuron_spawn_dissolve_delay_seconds {
+        proposed.neuron_spawn_dissolve_delay_seconds = v;
+    }
+    if let Some(v) = opts.max_proposals_to_keep_per_topic {
+        proposed.max_proposals_to_keep_per_topic = v;
+    }
+
+    print_comparison(&current, &proposed);
+
+    super::submit(Action::ManageNetworkEconomics(proposed), proposal_opts, env).await
+}
@@ -1,40 +1,233 @@
+use crate::lib::client::governance::GovernanceClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::format_action;
+use crate::lib::prompt::confirm;
 
+use anyhow::{anyhow, bail};
 use clap::Clap;
+use ic_nns_governance::pb::v1::proposal::Action;
+use ic_nns_governance::pb::v1::{ExecuteNnsFunction, NnsFunction, Proposal};
+use std::io::Read;
+use std::path::PathBuf;
 
+mod add_node_operator;
+mod add_nodes_to_subnet;
+mod add_or_remove_node_provider;
 mod authorize_to_subnet;
+mod create_subnet;
+mod execute_nns_function;
+mod manage_network_economics;
 mod motion;
+mod proposal_file;
+mod remove_nodes_from_subnet;
+mod reward_node_provider;
+mod update_icp_xdr_rate;
+mod update_subnet_config;
+mod upgrade_nns_canister;
+
+// Limits the governance canister enforces on submitted proposals.
+const PROPOSAL_SUMMARY_BYTES_MAX: usize = 15000;
+const PROPOSAL_URL_CHAR_MIN: usize = 10;
+const PROPOSAL_URL_CHAR_MAX: usize = 2048;
 
 /// Submit a proposal
 #[derive(Clap, Clone)]
 #[clap(name("governance"))]
 pub struct SubmitProposalOpts {
     #[clap(subcommand)]
-    subcmd: SubCommand,
+    subcmd: Option<SubCommand>,
 
     /// Neuron id
-    neuron_id: u64,
+    neuron_id: Option<u64>,
+
+    /// Title. The governance canister has no title field, so the title is
+    /// prepended to the summary as a Markdown heading.
+    #[clap(long)]
+    title: Option<String>,
 
     /// Summary
+    #[clap(long, conflicts_with("summary-file"))]
+    summary: Option<String>,
+
+    /// Read the summary from a Markdown file, or from stdin if `-` is given.
+    /// Reading from stdin requires `--yes` as the proposal cannot be confirmed.
     #[clap(long)]
-    summary: String,
+    summary_file: Option<PathBuf>,
 
     /// Url
     #[clap(long)]
-    url: String,
+    url: Option<String>,
+
+    /// Read the neuron id, title, summary, url and action of the proposal from a TOML file
+    #[clap(
+        long,
+        conflicts_with_all(&["neuron-id", "title", "summary", "summary-file", "url"])
+    )]
+    from_file: Option<PathBuf>,
 }
 
 #[derive(Clap, Clone)]
 enum SubCommand {
+    #[clap(alias("assign-node-operator"))]
+    AddNodeOperator(add_node_operator::AddNodeOperatorOpts),
+    AddNodesToSubnet(add_nodes_to_subnet::AddNodesToSubnetOpts),
+    AddOrRemoveNodeProvider(add_or_remove_node_provider::AddOrRemoveNodeProviderOpts),
     AuthorizeToSubnet(authorize_to_subnet::AuthToSubnetOpts),
+    CreateSubnet(create_subnet::CreateSubnetOpts),
+    ExecuteNnsFunction(execute_nns_function::ExecuteNnsFunctionOpts),
+    ManageNetworkEconomics(manage_network_economics::ManageNetworkEconomicsOpts),
     Motion(motion::MotionOpts),
+    RemoveNodesFromSubnet(remove_nodes_from_subnet::RemoveNodesFromSubnetOpts),
+    RewardNodeProvider(reward_node_provider::RewardNodeProviderOpts),
+    UpdateIcpXdrRate(update_icp_xdr_rate::UpdateIcpXdrRateOpts),
+    UpdateSubnetConfig(update_subnet_config::UpdateSubnetConfigOpts),
+    UpgradeNnsCanister(upgrade_nns_canister::UpgradeNnsCanisterOpts),
 }
 
 pub async fn exec(opts: SubmitProposalOpts, env: Env) -> NnsCliResult {
+    let opts = if let Some(path) = opts.from_file.clone() {
+        if opts.subcmd.is_some() {
+            bail!("The action is read from the proposal file, omit the subcommand.");
+        }
+        proposal_file::read_proposal_file(&path)?
+    } else {
+        opts
+    };
     let proposal_opts = opts.clone();
-    match opts.subcmd {
+    let subcmd = opts
+        .subcmd
+        .ok_or_else(|| anyhow!("Please specify the proposal's action or a --from-file"))?;
+    match subcmd {
+        SubCommand::AddNodeOperator(v) => add_node_operator::exec(v, proposal_opts, env).await,
+        SubCommand::AddNodesToSubnet(v) => add_nodes_to_subnet::exec(v, proposal_opts, env).await,
+        SubCommand::AddOrRemoveNodeProvider(v) => {
+            add_or_remove_node_provider::exec(v, proposal_opts, env).await
+        }
         SubCommand::AuthorizeToSubnet(v) => authorize_to_subnet::exec(v, proposal_opts, env).await,
+        SubCommand::CreateSubnet(v) => create_subnet::exec(v, proposal_opts, env).await,
+        SubCommand::ExecuteNnsFunction(v) => {
+            execute_nns_function::exec(v, proposal_opts, env).await
+        }
+        SubCommand::ManageNetworkEconomics(v) => {
+            manage_network_economics::exec(v, proposal_opts, env).await
+        }
         SubCommand::Motion(v) => motion::exec(v, proposal_opts, env).await,
+        SubCommand::RemoveNodesFromSubnet(v) => {
+            remove_nodes_from_subnet::exec(v, proposal_opts, env).await
+        }
+        SubCommand::RewardNodeProvider(v) => {
+            reward_node_provider::exec(v, proposal_opts, env).await
+        }
+        SubCommand::UpdateIcpXdrRate(v) => update_icp_xdr_rate::exec(v, proposal_opts, env).await,
+        SubCommand::UpdateSubnetConfig(v) => {
+            update_subnet_config::exec(v, proposal_opts, env).await
+        }
+        SubCommand::UpgradeNnsCanister(v) => {
+            upgrade_nns_canister::exec(v, proposal_opts, env).await
+        }
+    }
+}
+
+fn read_summary(proposal_opts: &SubmitProposalOpts, env: &Env) -> NnsCliResult<String> {
+    let summary = match (&proposal_opts.summary, &proposal_opts.summary_file) {
+        (Some(summary), None) => summary.clone(),
+        (None, Some(path)) if path.to_str() == Some("-") => {
+            // The confirmation prompt would read from the same stdin.
+            if !env.yes && !env.dry_run {
+                bail!("Reading the summary from stdin requires --yes, as the proposal cannot be confirmed.");
+            }
+            let mut summary = String::new();
+            std::io::stdin().read_to_string(&mut summary)?;
+            summary
+        }
+        (None, Some(path)) => std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("Could not read {}: {}", path.display(), err))?,
+        _ => bail!("Please specify the summary with either --summary or --summary-file"),
+    };
+    match &proposal_opts.title {
+        Some(title) => Ok(format!("# {}\n\n{}", title, summary)),
+        None => Ok(summary),
+    }
+}
+
+/// Checks the proposal against the governance canister's limits before it is signed.
+fn validate(proposal: &Proposal) -> NnsCliResult {
+    if proposal.summary.len() > PROPOSAL_SUMMARY_BYTES_MAX {
+        bail!(
+            "The summary is {} bytes long, but at most {} bytes are allowed.",
+            proposal.summary.len(),
+            PROPOSAL_SUMMARY_BYTES_MAX
+        );
     }
+    let url_chars = proposal.url.chars().count();
+    if !proposal.url.is_empty()
+        && !(PROPOSAL_URL_CHAR_MIN..=PROPOSAL_URL_CHAR_MAX).contains(&url_chars)
+    {
+        bail!(
+            "The url is {} characters long, but must be between {} and {} characters.",
+            url_chars,
+            PROPOSAL_URL_CHAR_MIN,
+            PROPOSAL_URL_CHAR_MAX
+        );
+    }
+    Ok(())
+}
+
+fn print_preview(proposal: &Proposal) {
+    println!("Summary:\n{}\n", proposal.summary.trim_end());
+    println!("Url: {}\n", proposal.url);
+    if let Some(action) = &proposal.action {
+        println!("Action: {}\n", format_action(action));
+    }
+}
+
+/// Makes a proposal with the given action from the neuron in `proposal_opts`.
+async fn submit(action: Action, proposal_opts: SubmitProposalOpts, env: Env) -> NnsCliResult {
+    let neuron_id = proposal_opts
+        .neuron_id
+        .ok_or_else(|| anyhow!("Please specify the id of the neuron making the proposal"))?;
+    let proposal = Proposal {
+        summary: read_summary(&proposal_opts, &env)?,
+        url: proposal_opts
+            .url
+            .ok_or_else(|| anyhow!("Please specify the proposal's --url"))?,
+        action: Some(action),
+    };
+    validate(&proposal)?;
+    print_preview(&proposal);
+    confirm(
+        &env,
+        &format!("Submit this proposal from neuron {}", neuron_id),
+    )?;
+
+    let response = GovernanceClient::new(&env)
+        .make_proposal(neuron_id, proposal)
+        .await?
+        .map_err(|gov_err| anyhow!("{}", gov_err))?;
+    match response.proposal_id {
+        Some(proposal_id) => println!("{:?}", proposal_id),
+        None => eprintln!("Propsal sent but did not receive a proposal id in response."),
+    };
+
+    NnsCliResult::Ok(())
+}
+
+/// Makes a proposal to execute `nns_function` with the candid encoded `payload`.
+async fn submit_nns_function(
+    nns_function: NnsFunction,
+    payload: Vec<u8>,
+    proposal_opts: SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let execute_nns_function = ExecuteNnsFunction {
+        nns_function: nns_function as i32,
+        payload,
+    };
+    submit(
+        Action::ExecuteNnsFunction(execute_nns_function),
+        proposal_opts,
+        env,
+    )
+    .await
 }
@@ -0,0 +1,58 @@
+use crate::lib::error::NnsCliResult;
+
+use anyhow::anyhow;
+use clap::Clap;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// A proposal described in a TOML file, i.e.
+///
+/// ```toml
+/// neuron_id = 42
+/// title = "Create an application subnet"
+/// summary_file = "create-subnet.md"
+/// url = "https://forum.dfinity.org/t/..."
+/// action = ["create-subnet", "--replica-version-id", "...", "--node-ids", "..."]
+/// ```
+///
+/// `action` holds the arguments of one of the `submit-proposal` subcommands.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProposalFile {
+    neuron_id: u64,
+    title: Option<String>,
+    summary: Option<String>,
+    /// Relative to the proposal file.
+    summary_file: Option<PathBuf>,
+    url: String,
+    action: Vec<String>,
+}
+
+#[derive(Clap)]
+struct ActionOpts {
+    #[clap(subcommand)]
+    subcmd: super::SubCommand,
+}
+
+pub fn read_proposal_file(path: &Path) -> NnsCliResult<super::SubmitProposalOpts> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| anyhow!("Could not read {}: {}", path.display(), err))?;
+    let file: ProposalFile = toml::from_str(&content)
+        .map_err(|err| anyhow!("Could not parse {}: {}", path.display(), err))?;
+
+    let action =
+        ActionOpts::try_parse_from(std::iter::once("action".to_string()).chain(file.action))?;
+    let summary_file = file
+        .summary_file
+        .map(|summary_file| path.with_file_name(summary_file));
+
+    Ok(super::SubmitProposalOpts {
+        subcmd: Some(action.subcmd),
+        neuron_id: Some(file.neuron_id),
+        title: file.title,
+        summary: file.summary,
+        summary_file,
+        url: Some(file.url),
+        from_file: None,
+    })
+}
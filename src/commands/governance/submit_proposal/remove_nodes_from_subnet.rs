@@ -0,0 +1,28 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::RemoveNodesFromSubnetPayload;
+
+use candid::Encode;
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::RemoveNodesFromSubnet;
+use ic_types::Principal;
+
+/// Submit a proposal to remove nodes from the subnets they are assigned to
+#[derive(Clap, Clone)]
+pub struct RemoveNodesFromSubnetOpts {
+    /// The ids of the nodes to remove.
+    #[clap(long, required = true)]
+    node_ids: Vec<Principal>,
+}
+
+pub async fn exec(
+    opts: RemoveNodesFromSubnetOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload = Encode!(&RemoveNodesFromSubnetPayload {
+        node_ids: opts.node_ids,
+    })?;
+
+    super::submit_nns_function(RemoveNodesFromSubnet, payload, proposal_opts, env).await
+}
@@ -0,0 +1,86 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::utils::{
+    duration_from_str, duration_validator, icpts_amount_validator, icpts_from_str,
+};
+
+use anyhow::{anyhow, bail};
+use clap::Clap;
+use ic_base_types::PrincipalId;
+use ic_nns_governance::pb::v1::proposal::Action;
+use ic_nns_governance::pb::v1::reward_node_provider::{
+    RewardMode, RewardToAccount, RewardToNeuron,
+};
+use ic_nns_governance::pb::v1::{NodeProvider, RewardNodeProvider};
+use ic_types::Principal;
+use ledger_canister::AccountIdentifier;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// Submit a proposal to reward a node provider, either by sending ICP to an
+/// account or by staking it in a new neuron
+#[derive(Clap, Clone)]
+pub struct RewardNodeProviderOpts {
+    /// The principal of the node provider.
+    #[clap(long)]
+    node_provider: Principal,
+
+    /// ICP to reward the node provider with.
+    /// Can be specified as a Decimal with the fractional portion up to 8 decimal places
+    /// i.e. 100.012
+    #[clap(long, validator(icpts_amount_validator))]
+    amount: String,
+
+    /// Send the reward to this AccountIdentifier.
+    #[clap(long, conflicts_with("to-neuron"))]
+    to_account: Option<String>,
+
+    /// Stake the reward in a new neuron controlled by the node provider.
+    #[clap(long, requires("dissolve-delay"))]
+    to_neuron: bool,
+
+    /// The dissolve delay of the new neuron, i.e. 180d.
+    #[clap(long, requires("to-neuron"), validator(duration_validator))]
+    dissolve_delay: Option<String>,
+}
+
+fn get_reward_mode(opts: &RewardNodeProviderOpts) -> NnsCliResult<RewardMode> {
+    match (&opts.to_account, opts.to_neuron) {
+        (Some(to_account), false) => {
+            let to_account = AccountIdentifier::from_str(to_account).map_err(|err| anyhow!(err))?;
+            Ok(RewardMode::RewardToAccount(RewardToAccount {
+                to_account: Some(ledger_canister::protobuf::AccountIdentifier::from(
+                    to_account,
+                )),
+            }))
+        }
+        (None, true) => {
+            let dissolve_delay = opts
+                .dissolve_delay
+                .as_ref()
+                .ok_or_else(|| anyhow!("Please specify the neuron's --dissolve-delay"))?;
+            Ok(RewardMode::RewardToNeuron(RewardToNeuron {
+                dissolve_delay_seconds: duration_from_str(dissolve_delay)?.as_secs(),
+            }))
+        }
+        _ => bail!("Please specify exactly one of --to-account or --to-neuron"),
+    }
+}
+
+pub async fn exec(
+    opts: RewardNodeProviderOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let reward_mode = get_reward_mode(&opts)?;
+    let node_provider = NodeProvider {
+        id: Some(PrincipalId::try_from(opts.node_provider.as_slice()).map_err(|err| anyhow!(err))?),
+    };
+    let action = Action::RewardNodeProvider(RewardNodeProvider {
+        node_provider: Some(node_provider),
+        amount_e8s: icpts_from_str(&opts.amount)?.get_e8s(),
+        reward_mode: Some(reward_mode),
+    });
+
+    super::submit(action, proposal_opts, env).await
+}
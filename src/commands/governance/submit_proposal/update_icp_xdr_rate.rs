@@ -0,0 +1,83 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::UpdateIcpXdrConversionRatePayload;
+
+use anyhow::anyhow;
+use candid::Encode;
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::IcpXdrConversionRate;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Submit a proposal to update the ICP/XDR conversion rate
+#[derive(Clap, Clone)]
+pub struct UpdateIcpXdrRateOpts {
+    /// The number of XDR one ICP is worth, with up to 4 decimal places i.e. 12.3456
+    #[clap(long, validator(xdr_rate_validator))]
+    rate: String,
+
+    /// When the rate was observed, in seconds since the Unix epoch.
+    #[clap(long)]
+    timestamp: u64,
+
+    /// Where the rate was obtained from.
+    #[clap(long, default_value = "")]
+    data_source: String,
+}
+
+fn xdr_rate_validator(rate: &str) -> Result<(), String> {
+    xdr_permyriad_from_str(rate)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+fn xdr_permyriad_from_str(rate: &str) -> NnsCliResult<u64> {
+    let rate = Decimal::from_str(rate).map_err(|e| anyhow!("Decimal conversion error: {}", e))?;
+    if rate.scale() > 4 {
+        return Err(anyhow!(
+            "The rate can only be specified to the 4th decimal."
+        ));
+    }
+    (rate * Decimal::new(10_000, 0))
+        .to_u64()
+        .ok_or_else(|| anyhow!("Could not convert {} to XDR permyriad", rate))
+}
+
+pub async fn exec(
+    opts: UpdateIcpXdrRateOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload = Encode!(&UpdateIcpXdrConversionRatePayload {
+        data_source: opts.data_source,
+        timestamp_seconds: opts.timestamp,
+        xdr_permyriad_per_icp: xdr_permyriad_from_str(&opts.rate)?,
+    })?;
+
+    super::submit_nns_function(IcpXdrConversionRate, payload, proposal_opts, env).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_rate_to_permyriad() {
+        assert_eq!(xdr_permyriad_from_str("1").unwrap(), 10_000);
+        assert_eq!(xdr_permyriad_from_str("42.5").unwrap(), 425_000);
+        assert_eq!(xdr_permyriad_from_str("1.2345").unwrap(), 12_345);
+        assert_eq!(xdr_permyriad_from_str("0.0001").unwrap(), 1);
+    }
+
+    #[test]
+    fn rejects_more_than_four_decimals() {
+        assert!(xdr_permyriad_from_str("1.23456").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_rates() {
+        assert!(xdr_permyriad_from_str("abc").is_err());
+        assert!(xdr_permyriad_from_str("-1").is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::{SubnetType, UpdateSubnetPayload};
+
+use candid::Encode;
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::UpdateConfigOfSubnet;
+use ic_types::Principal;
+
+/// Submit a proposal to update the configuration of a subnet.
+/// Only the given settings are changed.
+#[derive(Clap, Clone)]
+pub struct UpdateSubnetConfigOpts {
+    /// The subnet to update.
+    #[clap(long)]
+    subnet: Principal,
+
+    /// The new soft cap on the size of the ingress messages in a block, in bytes.
+    #[clap(long)]
+    ingress_bytes_per_block_soft_cap: Option<u64>,
+
+    /// The new maximum size of an ingress message, in bytes.
+    #[clap(long)]
+    max_ingress_bytes_per_message: Option<u64>,
+
+    /// The new maximum size of a block payload, in bytes.
+    #[clap(long)]
+    max_block_payload_size: Option<u64>,
+
+    /// The new unit delay of the consensus protocol, in milliseconds.
+    #[clap(long)]
+    unit_delay_millis: Option<u64>,
+
+    /// The new delay before notarizing a block, in milliseconds.
+    #[clap(long)]
+    initial_notary_delay_millis: Option<u64>,
+
+    /// The new number of blocks between distributed key generations.
+    #[clap(long)]
+    dkg_interval_length: Option<u64>,
+
+    /// The new maximum number of distributed key generation dealings in a block.
+    #[clap(long)]
+    dkg_dealings_per_block: Option<u64>,
+
+    /// Whether the subnet starts as the NNS subnet.
+    #[clap(long)]
+    start_as_nns: Option<bool>,
+
+    /// The new type of the subnet, which determines who may install canisters on it.
+    #[clap(long, possible_values = &["application", "system", "verified-application"])]
+    subnet_type: Option<SubnetType>,
+
+    /// Halt or resume the subnet.
+    #[clap(long)]
+    is_halted: Option<bool>,
+}
+
+pub async fn exec(
+    opts: UpdateSubnetConfigOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let payload = Encode!(&UpdateSubnetPayload {
+        subnet_id: opts.subnet,
+        ingress_bytes_per_block_soft_cap: opts.ingress_bytes_per_block_soft_cap,
+        max_ingress_bytes_per_message: opts.max_ingress_bytes_per_message,
+        max_block_payload_size: opts.max_block_payload_size,
+        unit_delay_millis: opts.unit_delay_millis,
+        initial_notary_delay_millis: opts.initial_notary_delay_millis,
+        dkg_interval_length: opts.dkg_interval_length,
+        dkg_dealings_per_block: opts.dkg_dealings_per_block,
+        start_as_nns: opts.start_as_nns,
+        subnet_type: opts.subnet_type,
+        is_halted: opts.is_halted,
+    })?;
+
+    super::submit_nns_function(UpdateConfigOfSubnet, payload, proposal_opts, env).await
+}
@@ -0,0 +1,67 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::{CanisterInstallMode, ChangeNnsCanisterProposalPayload};
+
+use candid::{Encode, IDLArgs};
+use clap::Clap;
+use ic_nns_governance::pb::v1::NnsFunction::NnsCanisterUpgrade;
+use ic_types::Principal;
+use openssl::sha::sha256;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Submit a proposal to upgrade or reinstall an NNS canister
+#[derive(Clap, Clone)]
+pub struct UpgradeNnsCanisterOpts {
+    /// The id of the NNS canister to upgrade.
+    #[clap(long)]
+    canister_id: Principal,
+
+    /// Path to the wasm module to install.
+    #[clap(long)]
+    wasm: PathBuf,
+
+    /// The argument to install the wasm module with, as Candid text.
+    #[clap(long)]
+    arg_candid: Option<String>,
+
+    /// Whether to upgrade the canister or to reinstall it, wiping its state.
+    #[clap(long, possible_values = &["upgrade", "reinstall"], default_value = "upgrade")]
+    mode: String,
+}
+
+pub async fn exec(
+    opts: UpgradeNnsCanisterOpts,
+    proposal_opts: super::SubmitProposalOpts,
+    env: Env,
+) -> NnsCliResult {
+    let wasm_module = tokio::fs::read(&opts.wasm).await?;
+    println!(
+        "Wasm module SHA-256: {} ({} bytes)",
+        hex::encode(sha256(&wasm_module)),
+        wasm_module.len()
+    );
+
+    let arg = match opts.arg_candid {
+        Some(text) => IDLArgs::from_str(&text)?.to_bytes()?,
+        None => Encode!()?,
+    };
+    let mode = match opts.mode.as_str() {
+        "upgrade" => CanisterInstallMode::Upgrade,
+        "reinstall" => CanisterInstallMode::Reinstall,
+        _ => unreachable!(),
+    };
+
+    let payload = Encode!(&ChangeNnsCanisterProposalPayload {
+        stop_before_installing: true,
+        mode,
+        canister_id: opts.canister_id,
+        wasm_module,
+        arg,
+        compute_allocation: None,
+        memory_allocation: None,
+        query_allocation: None,
+    })?;
+
+    super::submit_nns_function(NnsCanisterUpgrade, payload, proposal_opts, env).await
+}
@@ -0,0 +1,68 @@
+use crate::lib::client::governance::GovernanceClient;
+use crate::lib::env::Env;
+use crate::lib::error::{EarlyExit, NnsCliResult};
+use crate::lib::nns_types::governance::{enum_name, format_tally};
+use crate::lib::nns_types::utils::{duration_from_str, duration_validator, format_timestamp};
+
+use anyhow::anyhow;
+use clap::Clap;
+use ic_nns_governance::pb::v1::ProposalStatus;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const EXIT_EXECUTED: i32 = 0;
+const EXIT_ADOPTED: i32 = 2;
+const EXIT_REJECTED: i32 = 3;
+const EXIT_FAILED: i32 = 4;
+
+/// Poll a proposal until it is decided, printing its tally as it changes.
+/// Exits with 0 when the proposal was executed, 2 when it was adopted but not
+/// executed yet, 3 when it was rejected and 4 when its execution failed.
+#[derive(Clap)]
+pub struct WatchProposalOpts {
+    /// Proposal id
+    id: u64,
+
+    /// How often to poll the proposal, i.e. 30s
+    #[clap(long, default_value = "30s", validator(duration_validator))]
+    interval: String,
+}
+
+pub async fn exec(opts: WatchProposalOpts, env: Env) -> NnsCliResult {
+    let interval = duration_from_str(&opts.interval)?;
+    let governance = GovernanceClient::new(&env);
+    let mut last_seen = None;
+
+    loop {
+        let info = governance
+            .get_proposal_info(opts.id)
+            .await?
+            .ok_or_else(|| anyhow!("No proposal found with id {}", opts.id))?;
+
+        let status = ProposalStatus::from_i32(info.status);
+        let tally = info.latest_tally.as_ref().map(format_tally);
+        let current = (enum_name(status, info.status), tally);
+        if last_seen.as_ref() != Some(&current) {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            println!(
+                "[{}] {}: {}",
+                format_timestamp(now),
+                current.0,
+                current.1.as_deref().unwrap_or("no tally yet")
+            );
+            last_seen = Some(current);
+        }
+
+        let exit_code = match status {
+            Some(ProposalStatus::Executed) => Some(EXIT_EXECUTED),
+            Some(ProposalStatus::Adopted) => Some(EXIT_ADOPTED),
+            Some(ProposalStatus::Rejected) => Some(EXIT_REJECTED),
+            Some(ProposalStatus::Failed) => Some(EXIT_FAILED),
+            _ => None,
+        };
+        if let Some(exit_code) = exit_code {
+            return Err(EarlyExit(exit_code).into());
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
@@ -1,19 +1,110 @@
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::utils::{account_from_principal, subaccount_validator};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use clap::Clap;
-use ic_base_types::PrincipalId;
 use ledger_canister::AccountIdentifier;
-use std::convert::TryFrom;
 
-/// Prints the selected identity's AccountIdentifier.
+// An AccountIdentifier is a 4 byte CRC32 checksum followed by a 28 byte hash.
+const ACCOUNT_ID_CHECKSUM_BYTES: usize = 4;
+const ACCOUNT_ID_BYTES: usize = 32;
+
+/// Prints the selected identity's AccountIdentifier, or the one of another
+/// principal, or checks an AccountIdentifier for typos.
 #[derive(Clap)]
-pub struct AccountIdOpts {}
+pub struct AccountIdOpts {
+    /// Check that this AccountIdentifier is well formed instead
+    #[clap(long, conflicts_with_all(&["principal", "subaccount"]))]
+    check: Option<String>,
+
+    /// Compute the AccountIdentifier of this principal instead
+    #[clap(long)]
+    principal: Option<String>,
+
+    /// The subaccount, as 64 hex characters or an index
+    #[clap(long, validator(subaccount_validator))]
+    subaccount: Option<String>,
+}
+
+/// Explains what is wrong with a hex encoded AccountIdentifier, if anything.
+fn check_account_id(account_id: &str) -> NnsCliResult<AccountIdentifier> {
+    let expected_len = ACCOUNT_ID_BYTES * 2;
+    if account_id.len() != expected_len {
+        bail!(
+            "Expected {} hex characters but found {}. Check that the whole identifier was copied.",
+            expected_len,
+            account_id.len()
+        );
+    }
+    if let Some((idx, c)) = account_id
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_hexdigit())
+    {
+        bail!(
+            "Character {:?} at position {} is not a hex digit (0-9, a-f).",
+            c,
+            idx + 1
+        );
+    }
+
+    let bytes = hex::decode(account_id)?;
+    let (checksum, hash) = bytes.split_at(ACCOUNT_ID_CHECKSUM_BYTES);
+    let expected_checksum = crc32fast::hash(hash).to_be_bytes();
+    if checksum != expected_checksum {
+        bail!(
+            "The checksum {} does not match the expected {}. The identifier contains a typo.",
+            hex::encode(checksum),
+            hex::encode(expected_checksum)
+        );
+    }
+
+    account_id.parse().map_err(|err| anyhow!(err))
+}
 
-pub async fn exec(_opts: AccountIdOpts, env: Env) -> NnsCliResult {
-    let base_types_principal =
-        PrincipalId::try_from(env.sender.as_slice()).map_err(|err| anyhow!(err))?;
-    println!("{}", AccountIdentifier::new(base_types_principal, None));
+pub async fn exec(opts: AccountIdOpts, env: Env) -> NnsCliResult {
+    if let Some(account_id) = opts.check {
+        let account_id = check_account_id(&account_id.trim().to_lowercase())
+            .map_err(|err| anyhow!("Invalid AccountIdentifier: {}", err))?;
+        println!("{} is a valid AccountIdentifier", account_id);
+        return Ok(());
+    }
+
+    let principal = opts.principal.unwrap_or_else(|| env.sender.to_text());
+    let account_id = account_from_principal(&principal, opts.subaccount.as_deref())?;
+    println!("{}", account_id);
     NnsCliResult::Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "1c7a48ba6a562aa9eaa2481a9049cdf0433b9738c992d698c31d8abf89cadc79";
+
+    #[test]
+    fn accepts_valid_account_id() {
+        let account_id = check_account_id(VALID).unwrap();
+        assert_eq!(account_id.to_string(), VALID);
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        let err = check_account_id(&VALID[1..]).unwrap_err();
+        assert!(err.to_string().starts_with("Expected 64 hex characters"));
+    }
+
+    #[test]
+    fn rejects_non_hex_characters() {
+        let account_id = format!("{}z", &VALID[..63]);
+        let err = check_account_id(&account_id).unwrap_err();
+        assert!(err.to_string().contains("position 64"));
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let account_id = format!("{}0", &VALID[..63]);
+        let err = check_account_id(&account_id).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+    }
+}
@@ -1,49 +1,121 @@
+use crate::lib::client::ledger::LedgerClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
-use crate::lib::nns_types::governance::ledger_canister_id;
+use crate::lib::nns_types::utils::{account_from_principal, subaccount_validator};
 
-use anyhow::anyhow;
-use candid::{Decode, Encode};
+use anyhow::{anyhow, bail};
 use clap::Clap;
 use ic_base_types::PrincipalId;
-use ledger_canister::{AccountBalanceArgs, AccountIdentifier, ICPTs};
+use ledger_canister::{AccountIdentifier, ICPTs};
 use std::convert::TryFrom;
+use std::path::PathBuf;
 use std::str::FromStr;
 
-const ACCOUNT_BALANCE_METHOD: &str = "account_balance_dfx";
-
 /// Prints the account balance of the user
 #[derive(Clap)]
 pub struct BalanceOpts {
     /// Specifies an AccountIdentifier to get the balance of
+    #[clap(conflicts_with_all(&["principal", "file"]))]
     of: Option<String>,
+
+    /// Get the balance of an account of this principal instead
+    #[clap(long, conflicts_with("file"))]
+    principal: Option<String>,
+
+    /// The subaccount of --principal, as 64 hex characters or an index
+    #[clap(long, requires("principal"), validator(subaccount_validator))]
+    subaccount: Option<String>,
+
+    /// Get the balances of the accounts listed in a file and print them with
+    /// their total. Each line holds an AccountIdentifier, or a principal
+    /// optionally followed by a subaccount. Empty lines and lines starting
+    /// with `#` are ignored.
+    #[clap(long)]
+    file: Option<PathBuf>,
+
+    /// Fetch the balance with an update call so that the result is certified
+    /// by the subnet, instead of trusting the single replica answering a query
+    #[clap(long)]
+    certified: bool,
+}
+
+fn parse_accounts_file(contents: &str) -> NnsCliResult<Vec<AccountIdentifier>> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let account = match fields.as_slice() {
+                // AccountIdentifiers are 64 hex characters, principals are shorter.
+                [account] if account.len() == 64 => {
+                    AccountIdentifier::from_str(account).map_err(|err| anyhow!(err))
+                }
+                [principal] => account_from_principal(principal, None),
+                [principal, subaccount] => account_from_principal(principal, Some(subaccount)),
+                _ => Err(anyhow!(
+                    "expected an AccountIdentifier, or a principal and a subaccount"
+                )),
+            };
+            account.map_err(|err| anyhow!("Line {}: {}", line_number, err))
+        })
+        .collect()
+}
+
+async fn print_balances(
+    ledger: &LedgerClient<'_>,
+    accounts: Vec<AccountIdentifier>,
+    certified: bool,
+) -> NnsCliResult {
+    println!("{:<66}{:>20}", "Account", "Balance");
+    let mut total_e8s = 0_u64;
+    for account in accounts {
+        let balance = ledger.account_balance(account, certified).await?;
+        total_e8s += balance.get_e8s();
+        println!("{:<66}{:>20}", account.to_string(), balance.to_string());
+    }
+    println!(
+        "{:<66}{:>20}",
+        "Total",
+        ICPTs::from_e8s(total_e8s).to_string()
+    );
+    Ok(())
 }
 
 pub async fn exec(opts: BalanceOpts, env: Env) -> NnsCliResult {
-    let base_types_principal =
-        PrincipalId::try_from(env.sender.as_slice()).map_err(|err| anyhow!(err))?;
-    let acc_id = opts
-        .of
-        .map_or_else(
-            || Ok(AccountIdentifier::new(base_types_principal, None)),
-            |v| AccountIdentifier::from_str(&v),
-        )
-        .map_err(|err| anyhow!(err))?;
-
-    let ledger_canister_id = ledger_canister_id();
-
-    let arg = Encode!(&AccountBalanceArgs { account: acc_id })?;
-
-    let result = env
-        .agent
-        .query(&ledger_canister_id, ACCOUNT_BALANCE_METHOD)
-        .with_arg(arg)
-        .call()
-        .await?;
+    if let Some(path) = &opts.file {
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|err| anyhow!("Could not read {}: {}", path.display(), err))?;
+        let accounts = parse_accounts_file(&contents)?;
+        if accounts.is_empty() {
+            bail!("No accounts found in {}", path.display());
+        }
+        print_balances(&LedgerClient::new(&env), accounts, opts.certified).await?;
+        eprintln!("Certified: {}", if opts.certified { "yes" } else { "no" });
+        return Ok(());
+    }
+
+    let acc_id = if let Some(principal) = &opts.principal {
+        account_from_principal(principal, opts.subaccount.as_deref())?
+    } else {
+        let base_types_principal =
+            PrincipalId::try_from(env.sender.as_slice()).map_err(|err| anyhow!(err))?;
+        opts.of
+            .map_or_else(
+                || Ok(AccountIdentifier::new(base_types_principal, None)),
+                |v| AccountIdentifier::from_str(&v),
+            )
+            .map_err(|err| anyhow!(err))?
+    };
 
-    let balance = Decode!(&result, ICPTs)?;
+    let balance = LedgerClient::new(&env)
+        .account_balance(acc_id, opts.certified)
+        .await?;
 
     println!("{}", balance);
+    eprintln!("Certified: {}", if opts.certified { "yes" } else { "no" });
 
     Ok(())
 }
@@ -0,0 +1,64 @@
+use crate::lib::client::ledger::LedgerClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::utils::format_timestamp;
+
+use anyhow::anyhow;
+use clap::Clap;
+use ledger_canister::{BlockHeight, Transfer};
+
+/// Prints a single block of the ledger
+#[derive(Clap)]
+pub struct BlockOpts {
+    /// The height of the block
+    height: BlockHeight,
+}
+
+pub async fn exec(opts: BlockOpts, env: Env) -> NnsCliResult {
+    let block = LedgerClient::new(&env)
+        .block(opts.height)
+        .await?
+        .ok_or_else(|| anyhow!("Block {} does not exist yet", opts.height))?;
+
+    println!("{:<14}{}", "Height:", opts.height);
+    println!(
+        "{:<14}{}",
+        "Parent hash:",
+        block
+            .parent_hash
+            .map_or_else(|| "none".to_string(), |hash| hash.to_string())
+    );
+    println!(
+        "{:<14}{} ({} ns)",
+        "Timestamp:",
+        format_timestamp(block.timestamp.timestamp_nanos / 1_000_000_000),
+        block.timestamp.timestamp_nanos
+    );
+    println!("{:<14}{}", "Memo:", block.transaction.memo.0);
+    match block.transaction.transfer {
+        Transfer::Burn { from, amount } => {
+            println!("{:<14}Burn", "Type:");
+            println!("{:<14}{}", "From:", from);
+            println!("{:<14}{} ICP", "Amount:", amount);
+        }
+        Transfer::Mint { to, amount } => {
+            println!("{:<14}Mint", "Type:");
+            println!("{:<14}{}", "To:", to);
+            println!("{:<14}{} ICP", "Amount:", amount);
+        }
+        Transfer::Send {
+            from,
+            to,
+            amount,
+            fee,
+        } => {
+            println!("{:<14}Send", "Type:");
+            println!("{:<14}{}", "From:", from);
+            println!("{:<14}{}", "To:", to);
+            println!("{:<14}{} ICP", "Amount:", amount);
+            println!("{:<14}{} ICP", "Fee:", fee);
+        }
+    }
+
+    NnsCliResult::Ok(())
+}
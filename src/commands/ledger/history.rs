@@ -0,0 +1,116 @@
+use crate::lib::client::ledger::LedgerClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::utils::format_timestamp;
+
+use anyhow::anyhow;
+use clap::Clap;
+use ledger_canister::{AccountIdentifier, Block, BlockHeight, Transfer};
+use std::str::FromStr;
+
+/// Prints the transactions recorded in the ledger, newest first, optionally
+/// only those involving a given account
+#[derive(Clap)]
+pub struct HistoryOpts {
+    /// Only print transactions to or from this AccountIdentifier
+    #[clap(long)]
+    account: Option<String>,
+
+    /// Print the transactions from this block height onwards, oldest first,
+    /// instead of going back from the tip of the chain
+    #[clap(long)]
+    from_block: Option<BlockHeight>,
+
+    /// The maximum number of transactions to print
+    #[clap(long, default_value = "100")]
+    limit: u64,
+
+    /// The maximum number of blocks to fetch while looking for transactions
+    #[clap(long, default_value = "10000")]
+    scan_limit: u64,
+}
+
+fn involves_account(transfer: &Transfer, account: &AccountIdentifier) -> bool {
+    match transfer {
+        Transfer::Burn { from, .. } => from == account,
+        Transfer::Mint { to, .. } => to == account,
+        Transfer::Send { from, to, .. } => from == account || to == account,
+    }
+}
+
+fn print_header() {
+    println!(
+        "{:<10}{:<25}{:<6}{:>20}{:>12}{:>22}  {:<66}{}",
+        "Block", "Time", "Type", "Amount", "Fee", "Memo", "From", "To"
+    );
+}
+
+fn print_block(height: BlockHeight, block: &Block) {
+    let (kind, from, to, amount, fee) = match &block.transaction.transfer {
+        Transfer::Burn { from, amount } => {
+            ("Burn", from.to_string(), "-".to_string(), amount, None)
+        }
+        Transfer::Mint { to, amount } => ("Mint", "-".to_string(), to.to_string(), amount, None),
+        Transfer::Send {
+            from,
+            to,
+            amount,
+            fee,
+        } => ("Send", from.to_string(), to.to_string(), amount, Some(fee)),
+    };
+    println!(
+        "{:<10}{:<25}{:<6}{:>20}{:>12}{:>22}  {:<66}{}",
+        height,
+        format_timestamp(block.timestamp.timestamp_nanos / 1_000_000_000),
+        kind,
+        amount.to_string(),
+        fee.map_or_else(|| "-".to_string(), |fee| fee.to_string()),
+        block.transaction.memo.0,
+        from,
+        to
+    );
+}
+
+pub async fn exec(opts: HistoryOpts, env: Env) -> NnsCliResult {
+    let account = opts
+        .account
+        .as_deref()
+        .map(AccountIdentifier::from_str)
+        .transpose()
+        .map_err(|err| anyhow!(err))?;
+
+    let ledger = LedgerClient::new(&env);
+    let tip = ledger.tip_of_chain().await?.tip_index;
+    let heights: Box<dyn Iterator<Item = BlockHeight>> = match opts.from_block {
+        Some(from_block) => Box::new(from_block..=tip),
+        None => Box::new((0..=tip).rev()),
+    };
+
+    print_header();
+    let mut printed = 0;
+    for (scanned, height) in heights.enumerate() {
+        if printed == opts.limit {
+            break;
+        }
+        if scanned as u64 == opts.scan_limit {
+            eprintln!(
+                "Stopped at block {} after fetching {} blocks, pass a larger --scan-limit to search further.",
+                height, opts.scan_limit
+            );
+            break;
+        }
+        let block = ledger
+            .block(height)
+            .await?
+            .ok_or_else(|| anyhow!("Block {} does not exist", height))?;
+        if let Some(account) = &account {
+            if !involves_account(&block.transaction.transfer, account) {
+                continue;
+            }
+        }
+        print_block(height, &block);
+        printed += 1;
+    }
+
+    NnsCliResult::Ok(())
+}
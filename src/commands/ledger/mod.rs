@@ -5,6 +5,9 @@ use clap::Clap;
 
 mod account_id;
 mod balance;
+mod block;
+mod history;
+mod tip;
 
 /// Call the ledger canister
 #[derive(Clap)]
@@ -18,11 +21,17 @@ pub struct LedgerOpts {
 enum SubCommand {
     AccountId(account_id::AccountIdOpts),
     Balance(balance::BalanceOpts),
+    Block(block::BlockOpts),
+    History(history::HistoryOpts),
+    Tip(tip::TipOpts),
 }
 
 pub async fn exec(opts: LedgerOpts, env: Env) -> NnsCliResult {
     match opts.subcmd {
         SubCommand::AccountId(v) => account_id::exec(v, env).await,
         SubCommand::Balance(v) => balance::exec(v, env).await,
+        SubCommand::Block(v) => block::exec(v, env).await,
+        SubCommand::History(v) => history::exec(v, env).await,
+        SubCommand::Tip(v) => tip::exec(v, env).await,
     }
 }
@@ -0,0 +1,23 @@
+use crate::lib::client::ledger::LedgerClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+
+use clap::Clap;
+
+/// Prints the height of the latest block in the ledger and its certification
+#[derive(Clap)]
+pub struct TipOpts {}
+
+pub async fn exec(_opts: TipOpts, env: Env) -> NnsCliResult {
+    let tip = LedgerClient::new(&env).tip_of_chain().await?;
+
+    println!("{:<15}{}", "Tip index:", tip.tip_index);
+    println!(
+        "{:<15}{}",
+        "Certification:",
+        tip.certification
+            .map_or_else(|| "none".to_string(), hex::encode)
+    );
+
+    NnsCliResult::Ok(())
+}
@@ -7,6 +7,7 @@ mod governance;
 mod ledger;
 mod neuron;
 mod principal;
+mod request_status;
 
 #[derive(Clap)]
 pub enum Command {
@@ -14,6 +15,7 @@ pub enum Command {
     Governance(governance::GovernanceOpts),
     Ledger(ledger::LedgerOpts),
     Neuron(neuron::NeuronOpts),
+    RequestStatus(request_status::RequestStatusOpts),
 }
 
 pub async fn exec(cmd: Command, env: Env) -> NnsCliResult {
@@ -22,5 +24,6 @@ pub async fn exec(cmd: Command, env: Env) -> NnsCliResult {
         Command::Governance(v) => governance::exec(v, env).await,
         Command::Ledger(v) => ledger::exec(v, env).await,
         Command::Neuron(v) => neuron::exec(v, env).await,
+        Command::RequestStatus(v) => request_status::exec(v, env).await,
     }
 }
@@ -1,24 +1,18 @@
-use crate::lib::agent::create_waiter;
+use crate::lib::client::governance::GovernanceClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
-use crate::lib::nns_types::governance::governance_canister_id;
+use crate::lib::nns_types::governance::{dissolve_delay_seconds, neuron_stake_e8s};
+use crate::lib::nns_types::utils::format_duration;
+use crate::lib::prompt::confirm;
 
 use anyhow::anyhow;
-use candid::{Decode, Encode};
 use clap::Clap;
-use ic_nns_common::pb::v1::NeuronId as NeuronIdProto;
-use ic_nns_common::types::NeuronId;
-use ic_nns_governance::pb::v1::manage_neuron_response::Command::Error;
 use ic_nns_governance::pb::v1::{
     manage_neuron::configure::Operation,
-    manage_neuron::Command,
-    manage_neuron::Configure,
     manage_neuron::IncreaseDissolveDelay,
     manage_neuron::{StartDissolving, StopDissolving},
-    ManageNeuron, ManageNeuronResponse,
 };
-
-const MANAGE_NEURON_METHOD: &str = "manage_neuron";
+use ledger_canister::ICPTs;
 
 /// Configure the neuron's dissolve parameters
 #[derive(Clap, Clone)]
@@ -28,14 +22,10 @@ pub struct DissolveOpts {
     additional_delay_seconds: Option<u32>,
 }
 
-fn get_command(opts: DissolveOpts) -> NnsCliResult<Command> {
+fn get_operation(opts: DissolveOpts) -> NnsCliResult<Operation> {
     match opts.operation.as_str() {
-        "start" => Ok(Command::Configure(Configure {
-            operation: Some(Operation::StartDissolving(StartDissolving {})),
-        })),
-        "stop" => Ok(Command::Configure(Configure {
-            operation: Some(Operation::StopDissolving(StopDissolving {})),
-        })),
+        "start" => Ok(Operation::StartDissolving(StartDissolving {})),
+        "stop" => Ok(Operation::StopDissolving(StopDissolving {})),
         "increase-delay" => {
             let dissolve_delay = IncreaseDissolveDelay {
                 additional_dissolve_delay_seconds:
@@ -44,39 +34,74 @@ fn get_command(opts: DissolveOpts) -> NnsCliResult<Command> {
                             || anyhow!(
                                 "Please specify a dissolve dissolve delay i.e. `icx-nns neuron <id> dissolve increase-delay <additional-delay-seconds>"))?,
             };
-            Ok(Command::Configure(Configure {
-                operation: Some(Operation::IncreaseDissolveDelay(dissolve_delay)),
-            }))
+            Ok(Operation::IncreaseDissolveDelay(dissolve_delay))
         }
         _ => unreachable!(),
     }
 }
 
-pub async fn exec(opts: DissolveOpts, id: u64, env: Env) -> NnsCliResult {
-    let command = get_command(opts)?;
-    let id = NeuronId(id);
+/// Asks for confirmation before the operations that lock up or release the stake.
+/// The neuron is only looked up to describe the operation when actually prompting.
+async fn confirm_operation(
+    opts: &DissolveOpts,
+    id: u64,
+    governance: &GovernanceClient<'_>,
+    env: &Env,
+) -> NnsCliResult {
+    if opts.operation == "stop" {
+        return Ok(());
+    }
+    if env.yes || env.dry_run {
+        let additional_delay = opts.additional_delay_seconds.unwrap_or_default() as u64;
+        let summary = match opts.operation.as_str() {
+            "start" => format!("Start dissolving neuron {}", id),
+            _ => format!(
+                "Increase the dissolve delay of neuron {} by {}",
+                id,
+                format_duration(additional_delay)
+            ),
+        };
+        return confirm(env, &summary);
+    }
+
+    let neuron = governance
+        .get_full_neuron(id)
+        .await?
+        .map_err(|gov_err| anyhow!("{}", gov_err))?;
+    let stake = ICPTs::from_e8s(neuron_stake_e8s(&neuron));
+    let dissolve_delay = dissolve_delay_seconds(&neuron)?;
 
-    let manage_neuron = ManageNeuron {
-        id: Some(NeuronIdProto::from(id)),
-        command: Some(command),
+    let summary = match opts.operation.as_str() {
+        "start" => format!(
+            "Start dissolving neuron {} with {} ICP, dissolve delay {}",
+            id,
+            stake,
+            format_duration(dissolve_delay)
+        ),
+        _ => {
+            let additional_delay = opts.additional_delay_seconds.unwrap_or_default() as u64;
+            format!(
+                "Increase the dissolve delay of neuron {} with {} ICP from {} to {}",
+                id,
+                stake,
+                format_duration(dissolve_delay),
+                format_duration(dissolve_delay + additional_delay)
+            )
+        }
     };
-    let arg = Encode!(&manage_neuron)?;
+    confirm(env, &summary)
+}
 
-    let result = env
-        .agent
-        .update(&governance_canister_id(), MANAGE_NEURON_METHOD)
-        .with_arg(arg)
-        .call_and_wait(create_waiter())
-        .await?;
-    let manage_neuron_response = Decode!(&result, ManageNeuronResponse)?;
+pub async fn exec(opts: DissolveOpts, id: u64, env: Env) -> NnsCliResult {
+    let operation = get_operation(opts.clone())?;
+    let governance = GovernanceClient::new(&env);
+    confirm_operation(&opts, id, &governance, &env).await?;
 
-    match manage_neuron_response.command {
-        Some(Error(gov_err)) => println!("{}", gov_err),
-        Some(ic_nns_governance::pb::v1::manage_neuron_response::Command::Configure(
-            ic_nns_governance::pb::v1::manage_neuron_response::ConfigureResponse {},
-        )) => eprintln!("Configured succesfully."),
-        _ => eprintln!("Received an invalid response."),
-    };
+    governance
+        .configure(id, operation)
+        .await?
+        .map_err(|gov_err| anyhow!("{}", gov_err))?;
+    eprintln!("Configured successfully.");
 
     NnsCliResult::Ok(())
 }
@@ -0,0 +1,224 @@
+use crate::lib::client::governance::GovernanceClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::enum_name;
+
+use clap::Clap;
+use ic_nns_governance::pb::v1::Topic;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Print the neurons a neuron follows on each topic, recursively, flagging
+/// cycles and followees that have not voted recently
+#[derive(Clap)]
+pub struct FollowGraphOpts {
+    #[clap(long, possible_values = &["tree", "dot"], default_value = "tree")]
+    format: String,
+}
+
+/// What is known about a neuron in the follow graph.
+struct NeuronNode {
+    /// Followees per topic, if the neuron is readable by the caller.
+    followees: Option<BTreeMap<i32, Vec<u64>>>,
+    /// Whether the neuron has recent ballots.
+    has_voted: bool,
+}
+
+#[derive(Default)]
+struct FollowGraph {
+    nodes: HashMap<u64, Option<NeuronNode>>,
+    tree: Vec<String>,
+    edges: BTreeSet<(i32, u64, u64)>,
+    cycles: BTreeSet<String>,
+}
+
+async fn fetch_node(
+    governance: &GovernanceClient<'_>,
+    id: u64,
+) -> NnsCliResult<Option<NeuronNode>> {
+    if let Ok(neuron) = governance.get_full_neuron(id).await? {
+        let followees = neuron
+            .followees
+            .into_iter()
+            .map(|(topic, followees)| {
+                let ids = followees.followees.into_iter().map(|v| v.id).collect();
+                (topic, ids)
+            })
+            .collect();
+        return Ok(Some(NeuronNode {
+            followees: Some(followees),
+            has_voted: !neuron.recent_ballots.is_empty(),
+        }));
+    }
+    Ok(governance
+        .get_neuron_info(id)
+        .await?
+        .ok()
+        .map(|info| NeuronNode {
+            followees: None,
+            has_voted: !info.recent_ballots.is_empty(),
+        }))
+}
+
+impl FollowGraph {
+    async fn fetch(governance: &GovernanceClient<'_>, root: u64) -> NnsCliResult<Self> {
+        let mut graph = FollowGraph::default();
+        let mut queue = vec![root];
+        while let Some(id) = queue.pop() {
+            if graph.nodes.contains_key(&id) {
+                continue;
+            }
+            let node = fetch_node(governance, id).await?;
+            if let Some(followees) = node.as_ref().and_then(|v| v.followees.as_ref()) {
+                queue.extend(followees.values().flatten());
+            }
+            graph.nodes.insert(id, node);
+        }
+        Ok(graph)
+    }
+
+    /// The followees of a neuron on a topic. Following on `Unspecified`
+    /// applies to every topic except neuron management.
+    fn followees(&self, id: u64, topic: i32) -> Vec<u64> {
+        let followees = match self.nodes.get(&id) {
+            Some(Some(NeuronNode {
+                followees: Some(followees),
+                ..
+            })) => followees,
+            _ => return vec![],
+        };
+        followees
+            .get(&topic)
+            .or_else(|| {
+                if topic == Topic::NeuronManagement as i32 {
+                    None
+                } else {
+                    followees.get(&(Topic::Unspecified as i32))
+                }
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn annotation(&self, id: u64) -> &'static str {
+        match self.nodes.get(&id) {
+            Some(Some(node)) if !node.has_voted => " (dead end: has not voted recently)",
+            Some(Some(NeuronNode {
+                followees: None, ..
+            })) => " (followees not readable)",
+            Some(Some(_)) => "",
+            _ => " (not found)",
+        }
+    }
+
+    /// Adds the followees of `id` on `topic` to the tree. A neuron reached
+    /// through several paths is only expanded the first time.
+    fn walk(&mut self, topic: i32, id: u64, path: &mut Vec<u64>, shown: &mut HashSet<u64>) {
+        let indent = "  ".repeat(path.len() + 1);
+        if let Some(start) = path.iter().position(|v| *v == id) {
+            let cycle: Vec<String> = path[start..]
+                .iter()
+                .chain(std::iter::once(&id))
+                .map(|v| v.to_string())
+                .collect();
+            self.cycles.insert(format!(
+                "{}: {}",
+                enum_name(Topic::from_i32(topic), topic),
+                cycle.join(" -> ")
+            ));
+            self.tree.push(format!("{}{} (cycle)", indent, id));
+            return;
+        }
+        if !shown.insert(id) {
+            self.tree.push(format!("{}{} (already shown)", indent, id));
+            return;
+        }
+        self.tree
+            .push(format!("{}{}{}", indent, id, self.annotation(id)));
+
+        path.push(id);
+        for followee in self.followees(id, topic) {
+            self.edges.insert((topic, id, followee));
+            self.walk(topic, followee, path, shown);
+        }
+        path.pop();
+    }
+
+    fn print_tree(&self) {
+        for line in &self.tree {
+            println!("{}", line);
+        }
+        if !self.cycles.is_empty() {
+            println!("\nCycles:");
+            for cycle in &self.cycles {
+                println!("  {}", cycle);
+            }
+        }
+    }
+
+    fn print_dot(&self) {
+        println!("digraph follow_graph {{");
+        for cycle in &self.cycles {
+            println!("  // cycle on {}", cycle);
+        }
+        let mut ids: Vec<&u64> = self.nodes.keys().collect();
+        ids.sort();
+        for id in ids {
+            let style = match self.nodes.get(id) {
+                Some(Some(node)) if !node.has_voted => "color=red",
+                Some(Some(NeuronNode {
+                    followees: None, ..
+                })) => "style=dashed",
+                Some(Some(_)) => "",
+                _ => "color=red, style=dashed",
+            };
+            println!(
+                "  \"{}\" [label=\"{}{}\"{}{}];",
+                id,
+                id,
+                self.annotation(*id),
+                if style.is_empty() { "" } else { ", " },
+                style
+            );
+        }
+        for (topic, from, to) in &self.edges {
+            println!(
+                "  \"{}\" -> \"{}\" [label=\"{}\"];",
+                from,
+                to,
+                enum_name(Topic::from_i32(*topic), *topic)
+            );
+        }
+        println!("}}");
+    }
+}
+
+pub async fn exec(opts: FollowGraphOpts, id: u64, env: Env) -> NnsCliResult {
+    let mut graph = FollowGraph::fetch(&GovernanceClient::new(&env), id).await?;
+
+    let topics: Vec<i32> = match graph.nodes.get(&id) {
+        Some(Some(NeuronNode {
+            followees: Some(followees),
+            ..
+        })) => followees.keys().cloned().collect(),
+        _ => vec![],
+    };
+    if topics.is_empty() {
+        eprintln!("Neuron {} does not follow any neurons.", id);
+    }
+
+    for topic in topics {
+        graph.tree.push(format!(
+            "Topic {}:",
+            enum_name(Topic::from_i32(topic), topic)
+        ));
+        graph.walk(topic, id, &mut vec![], &mut HashSet::new());
+    }
+
+    match opts.format.as_str() {
+        "tree" => graph.print_tree(),
+        "dot" => graph.print_dot(),
+        _ => unreachable!(),
+    }
+
+    NnsCliResult::Ok(())
+}
@@ -1,24 +1,17 @@
-use crate::lib::agent::create_waiter;
+use crate::lib::client::governance::GovernanceClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
-use crate::lib::nns_types::governance::governance_canister_id;
+use crate::lib::prompt::confirm;
 
 use anyhow::anyhow;
-use candid::{Decode, Encode};
 use clap::Clap;
 use ic_base_types::PrincipalId;
-use ic_nns_common::pb::v1::NeuronId as NeuronIdProto;
-use ic_nns_common::types::NeuronId;
-use ic_nns_governance::pb::v1::manage_neuron_response::Command::Error;
 use ic_nns_governance::pb::v1::{
-    manage_neuron::configure::Operation, manage_neuron::AddHotKey, manage_neuron::Command,
-    manage_neuron::Configure, manage_neuron::RemoveHotKey, ManageNeuron, ManageNeuronResponse,
+    manage_neuron::configure::Operation, manage_neuron::AddHotKey, manage_neuron::RemoveHotKey,
 };
 use ic_types::Principal;
 use std::convert::TryFrom;
 
-const MANAGE_NEURON_METHOD: &str = "manage_neuron";
-
 /// Configure the neuron's hot key parameters
 #[derive(Clap, Clone)]
 pub struct HotKeyOpts {
@@ -27,7 +20,7 @@ pub struct HotKeyOpts {
     operation: String,
 }
 
-fn get_command(operation: String, hot_key: Principal) -> NnsCliResult<Command> {
+fn get_operation(operation: String, hot_key: Principal) -> NnsCliResult<Operation> {
     match operation.as_str() {
         "add" => {
             let hot_key = AddHotKey {
@@ -37,9 +30,7 @@ fn get_command(operation: String, hot_key: Principal) -> NnsCliResult<Command> {
                     Some(base_types_principal)
                 },
             };
-            Ok(Command::Configure(Configure {
-                operation: Some(Operation::AddHotKey(hot_key)),
-            }))
+            Ok(Operation::AddHotKey(hot_key))
         }
         "remove" => {
             let hot_key = RemoveHotKey {
@@ -49,39 +40,26 @@ fn get_command(operation: String, hot_key: Principal) -> NnsCliResult<Command> {
                     Some(base_types_principal)
                 },
             };
-            Ok(Command::Configure(Configure {
-                operation: Some(Operation::RemoveHotKey(hot_key)),
-            }))
+            Ok(Operation::RemoveHotKey(hot_key))
         }
         _ => unreachable!(),
     }
 }
 
 pub async fn exec(opts: HotKeyOpts, id: u64, env: Env) -> NnsCliResult {
-    let command = get_command(opts.operation, opts.hot_key)?;
-    let id = NeuronId(id);
-
-    let manage_neuron = ManageNeuron {
-        id: Some(NeuronIdProto::from(id)),
-        command: Some(command),
-    };
-    let arg = Encode!(&manage_neuron)?;
-
-    let result = env
-        .agent
-        .update(&governance_canister_id(), MANAGE_NEURON_METHOD)
-        .with_arg(arg)
-        .call_and_wait(create_waiter())
-        .await?;
-    let manage_neuron_response = Decode!(&result, ManageNeuronResponse)?;
+    if opts.operation == "remove" {
+        confirm(
+            &env,
+            &format!("Remove hot key {} from neuron {}", opts.hot_key, id),
+        )?;
+    }
+    let operation = get_operation(opts.operation, opts.hot_key)?;
 
-    match manage_neuron_response.command {
-        Some(Error(gov_err)) => println!("{}", gov_err),
-        Some(ic_nns_governance::pb::v1::manage_neuron_response::Command::Configure(
-            ic_nns_governance::pb::v1::manage_neuron_response::ConfigureResponse {},
-        )) => eprintln!("Configured succesfully."),
-        _ => eprintln!("Received an invalid response."),
-    };
+    GovernanceClient::new(&env)
+        .configure(id, operation)
+        .await?
+        .map_err(|gov_err| anyhow!("{}", gov_err))?;
+    eprintln!("Configured successfully.");
 
     NnsCliResult::Ok(())
 }
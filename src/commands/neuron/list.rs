@@ -1,15 +1,11 @@
+use crate::lib::client::governance::GovernanceClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
-use crate::lib::nns_types::governance::governance_canister_id;
 
-use candid::{CandidType, Decode, Encode};
 use clap::Clap;
-use ic_nns_governance::pb::v1::ListNeuronsResponse;
-
-const LIST_NEURONS_METHOD: &str = "list_neurons";
 
 /// Call governance canister's list_neurons method
-#[derive(CandidType, Clap)]
+#[derive(Clap)]
 pub struct ListNeuronsOpts {
     // List of neuron ids
     #[clap(long)]
@@ -21,17 +17,10 @@ pub struct ListNeuronsOpts {
 }
 
 pub async fn exec(opts: ListNeuronsOpts, env: Env) -> NnsCliResult {
-    let arg = Encode!(&opts)?;
-
-    let result = env
-        .agent
-        .query(&governance_canister_id(), LIST_NEURONS_METHOD)
-        .with_arg(arg)
-        .call()
+    let neurons = GovernanceClient::new(&env)
+        .list_neurons(opts.neuron_ids, opts.include_neurons_readable_by_caller)
         .await?;
 
-    let neurons = Decode!(&result, ListNeuronsResponse)?;
-
     println!("{:?}", neurons);
 
     NnsCliResult::Ok(())
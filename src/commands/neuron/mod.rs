@@ -5,12 +5,15 @@ use anyhow::{anyhow, bail};
 use clap::Clap;
 
 mod dissolve;
+mod follow_graph;
 mod full_neuron;
 mod hot_key;
 mod ids;
 mod info;
 mod list;
+mod project;
 mod stake_or_refresh;
+mod summary;
 
 /// Manage neuron subcommand
 #[derive(Clap)]
@@ -23,39 +26,56 @@ pub struct NeuronOpts {
 
 #[derive(Clap)]
 enum SubCommand {
+    FollowGraph(follow_graph::FollowGraphOpts),
     FullInfo(full_neuron::GetFullNeuronOpts),
     HotKey(hot_key::HotKeyOpts),
     Ids(ids::GetNeuronIdOpts),
     Info(info::GetNeuronInfoOpts),
     List(list::ListNeuronsOpts),
+    Project(project::ProjectOpts),
     StakeOrRefresh(stake_or_refresh::StakeRefreshNeuronOpts),
     Dissolve(dissolve::DissolveOpts),
+    Summary(summary::NeuronSummaryOpts),
 }
 
-pub async fn exec(opts: NeuronOpts, env: Env) -> NnsCliResult {
-    let id = match opts.subcmd {
-        SubCommand::FullInfo(_)
-        | SubCommand::HotKey(_)
-        | SubCommand::Info(_)
-        | SubCommand::Dissolve(_) => opts.id.ok_or_else(|| {
-            anyhow!("Please specify a neuron id i.e. `icx-nns neuron <id> <SUBCOMMAND>")
-        })?,
-        SubCommand::Ids(_) | SubCommand::List(_) | SubCommand::StakeOrRefresh(_) => {
-            if let Some(id) = opts.id {
-                bail!("Provided neuron id {} which is not needed for this command. Omit the neuron id and execute the command again", id);
-            } else {
-                0_u64 // unused
-            }
-        }
-    };
+/// The neuron id of a subcommand acting on one neuron.
+fn required_id(id: Option<u64>) -> NnsCliResult<u64> {
+    id.ok_or_else(|| anyhow!("Please specify a neuron id i.e. `icx-nns neuron <id> <SUBCOMMAND>"))
+}
+
+/// Refuses a neuron id given to a subcommand that does not take one.
+fn no_id(id: Option<u64>) -> NnsCliResult {
+    if let Some(id) = id {
+        bail!("Provided neuron id {} which is not needed for this command. Omit the neuron id and execute the command again", id);
+    }
+    Ok(())
+}
 
+pub async fn exec(opts: NeuronOpts, env: Env) -> NnsCliResult {
+    let id = opts.id;
     match opts.subcmd {
-        SubCommand::FullInfo(v) => full_neuron::exec(v, id, env).await,
-        SubCommand::HotKey(v) => hot_key::exec(v, id, env).await,
-        SubCommand::Ids(v) => ids::exec(v, env).await,
-        SubCommand::Info(v) => info::exec(v, id, env).await,
-        SubCommand::List(v) => list::exec(v, env).await,
-        SubCommand::StakeOrRefresh(v) => stake_or_refresh::exec(v, env).await,
-        SubCommand::Dissolve(v) => dissolve::exec(v, id, env).await,
+        SubCommand::FollowGraph(v) => follow_graph::exec(v, required_id(id)?, env).await,
+        SubCommand::FullInfo(v) => full_neuron::exec(v, required_id(id)?, env).await,
+        SubCommand::HotKey(v) => hot_key::exec(v, required_id(id)?, env).await,
+        SubCommand::Ids(v) => {
+            no_id(id)?;
+            ids::exec(v, env).await
+        }
+        SubCommand::Info(v) => info::exec(v, required_id(id)?, env).await,
+        SubCommand::List(v) => {
+            no_id(id)?;
+            list::exec(v, env).await
+        }
+        // The neuron id is optional, the neuron can be described by flags instead.
+        SubCommand::Project(v) => project::exec(v, id, env).await,
+        SubCommand::StakeOrRefresh(v) => {
+            no_id(id)?;
+            stake_or_refresh::exec(v, env).await
+        }
+        SubCommand::Dissolve(v) => dissolve::exec(v, required_id(id)?, env).await,
+        SubCommand::Summary(v) => {
+            no_id(id)?;
+            summary::exec(v, env).await
+        }
     }
 }
@@ -0,0 +1,153 @@
+use crate::lib::client::governance::GovernanceClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::{dissolve_delay_seconds, neuron_stake_e8s, voting_power};
+use crate::lib::nns_types::utils::{
+    duration_from_str, duration_validator, format_duration, icpts_amount_validator, icpts_from_str,
+    ONE_YEAR_SECONDS,
+};
+
+use anyhow::{anyhow, bail};
+use clap::Clap;
+use ic_nns_governance::pb::v1::neuron::DissolveState;
+use ledger_canister::ICPTs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Compute a neuron's voting power and project its maturity over the coming
+/// years, either from a neuron id or offline from --stake, --delay and --age
+#[derive(Clap)]
+pub struct ProjectOpts {
+    /// The number of years to project
+    #[clap(long, default_value = "8")]
+    years: u64,
+
+    /// The assumed yearly maturity, as a percentage of voting power
+    #[clap(long, default_value = "10")]
+    reward_rate: f64,
+
+    /// Stake of the neuron in ICP, i.e. 100.012
+    #[clap(long, validator(icpts_amount_validator), requires("delay"))]
+    stake: Option<String>,
+
+    /// Dissolve delay, i.e. 2920d
+    #[clap(long, validator(duration_validator))]
+    delay: Option<String>,
+
+    /// Age, i.e. 365d
+    #[clap(long, validator(duration_validator))]
+    age: Option<String>,
+
+    /// Whether the neuron is dissolving
+    #[clap(long)]
+    dissolving: bool,
+}
+
+struct NeuronParameters {
+    stake_e8s: u64,
+    dissolve_delay_seconds: u64,
+    age_seconds: u64,
+    dissolving: bool,
+}
+
+async fn get_neuron_parameters(env: &Env, id: u64) -> NnsCliResult<NeuronParameters> {
+    let neuron = GovernanceClient::new(env)
+        .get_full_neuron(id)
+        .await?
+        .map_err(|gov_err| anyhow!("{}", gov_err))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let dissolving = matches!(
+        neuron.dissolve_state,
+        Some(DissolveState::WhenDissolvedTimestampSeconds(_))
+    );
+    Ok(NeuronParameters {
+        stake_e8s: neuron_stake_e8s(&neuron),
+        dissolve_delay_seconds: dissolve_delay_seconds(&neuron)?,
+        // Dissolving neurons do not age.
+        age_seconds: if dissolving {
+            0
+        } else {
+            now.saturating_sub(neuron.aging_since_timestamp_seconds)
+        },
+        dissolving,
+    })
+}
+
+fn get_offline_parameters(opts: &ProjectOpts) -> NnsCliResult<NeuronParameters> {
+    let stake = opts
+        .stake
+        .as_ref()
+        .ok_or_else(|| anyhow!("Please specify a neuron id, or --stake and --delay"))?;
+    let delay = opts
+        .delay
+        .as_ref()
+        .ok_or_else(|| anyhow!("Please specify the neuron's --delay"))?;
+    Ok(NeuronParameters {
+        stake_e8s: icpts_from_str(stake)?.get_e8s(),
+        dissolve_delay_seconds: duration_from_str(delay)?.as_secs(),
+        age_seconds: match &opts.age {
+            Some(age) if !opts.dissolving => duration_from_str(age)?.as_secs(),
+            _ => 0,
+        },
+        dissolving: opts.dissolving,
+    })
+}
+
+pub async fn exec(opts: ProjectOpts, id: Option<u64>, env: Env) -> NnsCliResult {
+    let mut neuron = match id {
+        Some(_) if opts.stake.is_some() || opts.delay.is_some() || opts.age.is_some() => {
+            bail!("Provide either a neuron id or --stake, --delay and --age, not both")
+        }
+        Some(id) => get_neuron_parameters(&env, id).await?,
+        None => get_offline_parameters(&opts)?,
+    };
+
+    println!("{:<16}{} ICP", "Stake:", ICPTs::from_e8s(neuron.stake_e8s));
+    println!(
+        "{:<16}{}",
+        "Dissolve delay:",
+        format_duration(neuron.dissolve_delay_seconds)
+    );
+    println!("{:<16}{}", "Age:", format_duration(neuron.age_seconds));
+    println!(
+        "{:<16}{}",
+        "Dissolving:",
+        if neuron.dissolving { "yes" } else { "no" }
+    );
+    println!(
+        "{:<16}{}% of voting power per year (assumed)",
+        "Reward rate:", opts.reward_rate
+    );
+    println!();
+
+    println!(
+        "{:<6}{:<18}{:<18}{:>20}{:>20}",
+        "Year", "Dissolve delay", "Age", "Voting power", "Maturity"
+    );
+    let mut maturity_e8s = 0_u64;
+    for year in 0..=opts.years {
+        let voting_power_e8s = voting_power(
+            neuron.stake_e8s,
+            neuron.dissolve_delay_seconds,
+            neuron.age_seconds,
+        );
+        println!(
+            "{:<6}{:<18}{:<18}{:>20}{:>20}",
+            year,
+            format_duration(neuron.dissolve_delay_seconds),
+            format_duration(neuron.age_seconds),
+            ICPTs::from_e8s(voting_power_e8s).to_string(),
+            ICPTs::from_e8s(maturity_e8s).to_string()
+        );
+
+        maturity_e8s += (voting_power_e8s as f64 * opts.reward_rate / 100.0) as u64;
+        if neuron.dissolving {
+            neuron.dissolve_delay_seconds = neuron
+                .dissolve_delay_seconds
+                .saturating_sub(ONE_YEAR_SECONDS);
+        } else {
+            neuron.age_seconds += ONE_YEAR_SECONDS;
+        }
+    }
+
+    NnsCliResult::Ok(())
+}
@@ -1,26 +1,29 @@
-use crate::lib::agent::create_waiter;
+use crate::lib::client::ledger::LedgerClient;
 use crate::lib::env::Env;
 use crate::lib::error::NnsCliResult;
-use crate::lib::nns_types::governance::{governance_canister_id, ledger_canister_id};
+use crate::lib::nns_types::governance::governance_canister_id;
 use crate::lib::nns_types::utils::{
     get_governance_subaccount, get_icpts_from_args, icpts_amount_validator, icpts_from_str,
 };
+use crate::lib::prompt::confirm;
 
 use anyhow::anyhow;
-use candid::{Decode, Encode};
 use clap::Clap;
-use ic_agent::Agent;
+use ic_agent::AgentError;
 use ic_base_types::{CanisterId, PrincipalId};
 use ic_nns_common::pb::v1::NeuronId;
 use ic_types::Principal;
 use ledger_canister::{
-    AccountIdentifier, BlockHeight, ICPTs, Memo, NotifyCanisterArgs, SendArgs, Subaccount,
+    AccountIdentifier, ICPTs, Memo, NotifyCanisterArgs, SendArgs, Subaccount, TimeStamp, Transfer,
     TRANSACTION_FEE,
 };
 use std::convert::TryFrom;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-const SEND_METHOD: &str = "send_dfx";
-const NOTIFY_METHOD: &str = "notify_dfx";
+// The reject code of a canister that trapped.
+const CANISTER_ERROR_REJECT_CODE: u64 = 5;
+// What the ledger traps with when a transfer is already on the chain.
+const DUPLICATE_TRANSACTION_MESSAGE: &str = "Transaction already exists on chain";
 
 /// Stake a new neuron or refresh an existing neuron
 #[derive(Clap)]
@@ -55,17 +58,36 @@ pub struct StakeRefreshNeuronOpts {
     /// Max fee, default is 10000 e8s.
     #[clap(long, validator(icpts_amount_validator))]
     max_fee: Option<String>,
+
+    /// Time the transfer is created at, in nanoseconds since the epoch.
+    /// Defaults to now. The ledger rejects a second transfer with the same
+    /// time, so pass the value printed by an earlier attempt to retry it.
+    #[clap(long)]
+    created_at_time: Option<u64>,
+}
+
+/// Whether the ledger rejected a send because the same transaction is
+/// already on the chain. The ledger traps with `DUPLICATE_TRANSACTION_MESSAGE`,
+/// which the replica embeds in its reject message.
+fn is_duplicate_transaction(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<AgentError>(),
+        Some(AgentError::ReplicaError { reject_code, reject_message })
+            if *reject_code == CANISTER_ERROR_REJECT_CODE
+                && reject_message.contains(DUPLICATE_TRANSACTION_MESSAGE)
+    )
 }
 
 async fn send_and_notify(
-    agent: Agent,
+    env: Env,
     memo: Memo,
     amount: ICPTs,
     fee: ICPTs,
     to_subaccount: Option<Subaccount>,
     max_fee: ICPTs,
+    created_at_time: TimeStamp,
 ) -> NnsCliResult<NeuronId> {
-    let ledger_canister_id = ledger_canister_id();
+    let ledger = LedgerClient::new(&env);
 
     let governance_canister_id = governance_canister_id();
 
@@ -74,37 +96,63 @@ async fn send_and_notify(
 
     let to = AccountIdentifier::new(gov_base_types_principal, to_subaccount);
 
-    let result = agent
-        .update(&ledger_canister_id, SEND_METHOD)
-        .with_arg(Encode!(&SendArgs {
-            memo,
-            amount,
-            fee,
-            from_subaccount: None,
-            to,
-            created_at_time: None,
-        })?)
-        .call_and_wait(create_waiter())
-        .await?;
-
-    let block_height = Decode!(&result, BlockHeight)?;
-    println!("Transfer sent at BlockHeight: {}", block_height);
-
-    let result = agent
-        .update(&ledger_canister_id, NOTIFY_METHOD)
-        .with_arg(Encode!(&NotifyCanisterArgs {
+    let send_args = SendArgs {
+        memo,
+        amount,
+        fee,
+        from_subaccount: None,
+        to,
+        created_at_time: Some(created_at_time),
+    };
+    // The block height is only known once the transfer is sent.
+    let notify_args = NotifyCanisterArgs {
+        block_height: 0,
+        max_fee,
+        from_subaccount: None,
+        to_canister: CanisterId::try_from(gov_base_types_principal).map_err(|err| anyhow!(err))?,
+        to_subaccount,
+    };
+    ledger.dry_run_send_and_notify(&send_args, &notify_args)?;
+
+    println!(
+        "Transfer created at time: {}",
+        created_at_time.timestamp_nanos
+    );
+    let block_height = match ledger.send(send_args).await {
+        Ok(block_height) => {
+            println!("Transfer sent at BlockHeight: {}", block_height);
+            block_height
+        }
+        Err(err) if is_duplicate_transaction(&err) => {
+            let from_principal =
+                PrincipalId::try_from(env.sender.as_slice()).map_err(|err| anyhow!(err))?;
+            let transfer = Transfer::Send {
+                from: AccountIdentifier::new(from_principal, None),
+                to,
+                amount,
+                fee,
+            };
+            let block_height = ledger
+                .find_transaction(memo, &transfer, created_at_time)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "The ledger rejected the transfer as a duplicate but the original could not be found: {}",
+                        err
+                    )
+                })?;
+            println!("Transfer was already sent at BlockHeight: {}", block_height);
+            block_height
+        }
+        Err(err) => return Err(err),
+    };
+
+    ledger
+        .notify(NotifyCanisterArgs {
             block_height,
-            max_fee,
-            from_subaccount: None,
-            to_canister: CanisterId::try_from(gov_base_types_principal)
-                .map_err(|err| anyhow!(err))?,
-            to_subaccount,
-        })?)
-        .call_and_wait(create_waiter())
-        .await?;
-
-    let result = Decode!(&result, NeuronId)?;
-    Ok(result)
+            ..notify_args
+        })
+        .await
 }
 
 pub async fn exec(opts: StakeRefreshNeuronOpts, env: Env) -> NnsCliResult {
@@ -117,9 +165,9 @@ pub async fn exec(opts: StakeRefreshNeuronOpts, env: Env) -> NnsCliResult {
 
     let memo = Memo(opts.memo);
 
+    let controller = Principal::from_text(opts.controller)?;
     let base_types_principal =
-        PrincipalId::try_from(Principal::from_text(opts.controller)?.as_slice())
-            .map_err(|err| anyhow!(err))?;
+        PrincipalId::try_from(controller.as_slice()).map_err(|err| anyhow!(err))?;
 
     let gov_subaccount = get_governance_subaccount(memo, base_types_principal);
 
@@ -130,7 +178,31 @@ pub async fn exec(opts: StakeRefreshNeuronOpts, env: Env) -> NnsCliResult {
         .map_or(Ok(TRANSACTION_FEE), |v| icpts_from_str(&v))
         .map_err(|err| anyhow!(err))?;
 
-    let result = send_and_notify(env.agent, memo, amount, fee, to_subaccount, max_fee).await?;
+    let created_at_time = match opts.created_at_time {
+        Some(timestamp_nanos) => timestamp_nanos,
+        None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64,
+    };
+
+    confirm(
+        &env,
+        &format!(
+            "Send {} ICP (fee {} ICP) to the neuron of {} with memo {}",
+            amount, fee, controller, memo.0
+        ),
+    )?;
+
+    let result = send_and_notify(
+        env,
+        memo,
+        amount,
+        fee,
+        to_subaccount,
+        max_fee,
+        TimeStamp {
+            timestamp_nanos: created_at_time,
+        },
+    )
+    .await?;
     println!("Neuron id: {:?}", result);
     Ok(())
 }
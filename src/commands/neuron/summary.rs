@@ -0,0 +1,95 @@
+use crate::lib::client::governance::GovernanceClient;
+use crate::lib::client::ledger::LedgerClient;
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::{enum_name, neuron_stake_e8s};
+use crate::lib::nns_types::utils::format_duration;
+
+use anyhow::anyhow;
+use clap::Clap;
+use ic_base_types::PrincipalId;
+use ic_nns_governance::pb::v1::{Neuron, NeuronState};
+use ledger_canister::{AccountIdentifier, ICPTs};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+
+/// Print a table of your neurons with their stake, maturity and voting power,
+/// along with the liquid balance of your account
+#[derive(Clap)]
+pub struct NeuronSummaryOpts {
+    /// Include neurons readable by caller, i.e. those you are a hot key of
+    #[clap(long)]
+    include_neurons_readable_by_caller: bool,
+}
+
+fn icpts(e8s: u64) -> String {
+    ICPTs::from_e8s(e8s).to_string()
+}
+
+pub async fn exec(opts: NeuronSummaryOpts, env: Env) -> NnsCliResult {
+    let governance = GovernanceClient::new(&env);
+    let ids = governance.get_neuron_ids().await?;
+    let response = governance
+        .list_neurons(ids, opts.include_neurons_readable_by_caller)
+        .await?;
+
+    let full_neurons: HashMap<u64, Neuron> = response
+        .full_neurons
+        .into_iter()
+        .filter_map(|neuron| neuron.id.clone().map(|id| (id.id, neuron)))
+        .collect();
+    let neuron_infos: BTreeMap<_, _> = response.neuron_infos.into_iter().collect();
+
+    println!(
+        "{:<22}{:>20}{:>20}  {:<12}{:<18}{:<18}{:>20}",
+        "Neuron", "Stake", "Maturity", "State", "Dissolve delay", "Age", "Voting power"
+    );
+    let (mut total_stake, mut total_maturity, mut total_voting_power) = (0, 0, 0);
+    for (id, info) in &neuron_infos {
+        let (stake, maturity) = full_neurons.get(id).map_or((0, 0), |neuron| {
+            (neuron_stake_e8s(neuron), neuron.maturity_e8s_equivalent)
+        });
+        let state = enum_name(NeuronState::from_i32(info.state), info.state);
+        println!(
+            "{:<22}{:>20}{:>20}  {:<12}{:<18}{:<18}{:>20}",
+            id,
+            icpts(stake),
+            icpts(maturity),
+            state,
+            format_duration(info.dissolve_delay_seconds),
+            format_duration(info.age_seconds),
+            icpts(info.voting_power)
+        );
+        total_stake += stake;
+        total_maturity += maturity;
+        total_voting_power += info.voting_power;
+    }
+
+    let base_types_principal =
+        PrincipalId::try_from(env.sender.as_slice()).map_err(|err| anyhow!(err))?;
+    let balance = LedgerClient::new(&env)
+        .account_balance(AccountIdentifier::new(base_types_principal, None), false)
+        .await?;
+
+    println!(
+        "{:<22}{:>20}{:>20}  {:<12}{:<18}{:<18}{:>20}",
+        "Total",
+        icpts(total_stake),
+        icpts(total_maturity),
+        "",
+        "",
+        "",
+        icpts(total_voting_power)
+    );
+    println!();
+    println!("{:<22}{:>20}", "Liquid balance", balance.to_string());
+    println!(
+        "{:<22}{:>20}",
+        "Total ICP",
+        (balance + ICPTs::from_e8s(total_stake))
+            .map_err(|err| anyhow!(err))?
+            .to_string()
+    );
+
+    NnsCliResult::Ok(())
+}
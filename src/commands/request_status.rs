@@ -0,0 +1,72 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+
+use anyhow::{anyhow, bail};
+use candid::{Decode, IDLArgs};
+use clap::Clap;
+use ic_agent::agent::{Replied, RequestStatusResponse};
+use ic_agent::RequestId;
+use ic_nns_common::pb::v1::NeuronId;
+use ic_nns_governance::pb::v1::ManageNeuronResponse;
+use ic_types::Principal;
+use ledger_canister::BlockHeight;
+use std::str::FromStr;
+
+/// Prints the status of an update call sent earlier, and its reply if it has
+/// been answered
+#[derive(Clap)]
+pub struct RequestStatusOpts {
+    /// The canister the update call was sent to
+    canister_id: Principal,
+
+    /// The request id printed when the update call was sent, i.e. 0x1234...
+    request_id: String,
+
+    /// Decode the reply as this type instead of printing it as candid
+    #[clap(long, possible_values(&["manage-neuron-response", "block-height", "neuron-id"]))]
+    decode_as: Option<String>,
+}
+
+fn print_reply(reply: &[u8], decode_as: Option<&str>) -> NnsCliResult {
+    match decode_as {
+        Some("manage-neuron-response") => {
+            println!("{:#?}", Decode!(reply, ManageNeuronResponse)?)
+        }
+        Some("block-height") => println!("BlockHeight: {}", Decode!(reply, BlockHeight)?),
+        Some("neuron-id") => println!("Neuron id: {:?}", Decode!(reply, NeuronId)?),
+        _ => println!("{}", IDLArgs::from_bytes(reply)?),
+    }
+    Ok(())
+}
+
+pub async fn exec(opts: RequestStatusOpts, env: Env) -> NnsCliResult {
+    let request_id = RequestId::from_str(opts.request_id.trim_start_matches("0x"))
+        .map_err(|err| anyhow!("Invalid request id {}: {:?}", opts.request_id, err))?;
+
+    match env
+        .agent
+        .request_status_raw(&request_id, opts.canister_id)
+        .await?
+    {
+        RequestStatusResponse::Replied {
+            reply: Replied::CallReplied(reply),
+        } => {
+            println!("Status: replied");
+            print_reply(&reply, opts.decode_as.as_deref())?;
+        }
+        RequestStatusResponse::Rejected {
+            reject_code,
+            reject_message,
+        } => bail!("Rejected (code {}): {}", reject_code, reject_message),
+        RequestStatusResponse::Done => {
+            println!("Status: done, the reply is no longer available")
+        }
+        RequestStatusResponse::Received => println!("Status: received"),
+        RequestStatusResponse::Processing => println!("Status: processing"),
+        RequestStatusResponse::Unknown => {
+            println!("Status: unknown, the request was not received or has expired")
+        }
+    }
+
+    NnsCliResult::Ok(())
+}
@@ -1,15 +1,68 @@
-use crate::lib::error::NnsCliResult;
+use crate::lib::env::Env;
+use crate::lib::error::{EarlyExit, NnsCliResult};
 
 use anyhow::anyhow;
-use garcon::Delay;
-use ic_agent::{Agent, Identity};
+use candid::IDLArgs;
+use garcon::{Delay, Waiter};
+use ic_agent::agent::{Replied, RequestStatusResponse};
+use ic_agent::{Agent, AgentError, Identity, RequestId};
+use ic_types::Principal;
+use std::time::Duration;
 
-pub fn create_waiter() -> Delay {
+// The delay before the first retry of a failed call, doubled on every retry.
+const RETRY_BACKOFF_INITIAL: Duration = Duration::from_millis(500);
+
+pub fn create_waiter(timeout: Duration) -> Delay {
     Delay::builder()
-        .throttle(std::time::Duration::from_secs(1))
+        .throttle(Duration::from_secs(1))
+        .timeout(timeout)
         .build()
 }
 
+/// Whether a call failed for a reason that may go away by itself, i.e. the
+/// connection dropped or the boundary node was overloaded.
+fn is_transient(err: &AgentError) -> bool {
+    match err {
+        AgentError::TransportError(_) | AgentError::TimeoutWaitingForResponse() => true,
+        AgentError::HttpError(payload) => payload.status >= 500,
+        _ => false,
+    }
+}
+
+/// Makes a query call, retrying up to `--retries` times with exponential
+/// backoff on transient errors. Queries have no side effects, so this is safe.
+pub async fn query(
+    env: &Env,
+    canister_id: &Principal,
+    method_name: &str,
+    arg: Vec<u8>,
+) -> NnsCliResult<Vec<u8>> {
+    let mut backoff = RETRY_BACKOFF_INITIAL;
+    let mut attempt = 0;
+    loop {
+        let result = env
+            .agent
+            .query(canister_id, method_name)
+            .with_arg(arg.clone())
+            .call()
+            .await;
+        match result {
+            Err(err) if attempt < env.retries && is_transient(&err) => {
+                eprintln!(
+                    "Query {} failed, retrying in {}ms: {}",
+                    method_name,
+                    backoff.as_millis(),
+                    err
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            result => return Ok(result?),
+        }
+    }
+}
+
 pub async fn construct_agent(
     identity: Box<dyn Identity + Send + Sync>,
     endpoint: String,
@@ -25,3 +78,108 @@ pub async fn construct_agent(
     }
     Ok(agent)
 }
+
+/// Prints the update call that would be sent to the canister.
+pub fn print_dry_run(canister_id: &Principal, method_name: &str, arg: &[u8]) -> NnsCliResult {
+    let decoded = IDLArgs::from_bytes(arg)?;
+    println!("Canister id: {}", canister_id);
+    println!("Method name: {}", method_name);
+    println!("Argument (candid): {}", decoded);
+    println!("Argument (hex): {}", hex::encode(arg));
+    Ok(())
+}
+
+/// Polls the status of a submitted update until it is answered or `--timeout`
+/// expires. Up to `--retries` transient errors in a row are retried with
+/// exponential backoff, a rejection is returned as `AgentError::ReplicaError`.
+pub async fn wait_for_reply(
+    env: &Env,
+    canister_id: &Principal,
+    request_id: &RequestId,
+) -> NnsCliResult<Vec<u8>> {
+    let mut waiter = create_waiter(env.timeout);
+    waiter.start();
+    let mut backoff = RETRY_BACKOFF_INITIAL;
+    let mut failures = 0;
+    loop {
+        match env
+            .agent
+            .request_status_raw(request_id, canister_id.clone())
+            .await
+        {
+            Ok(RequestStatusResponse::Replied {
+                reply: Replied::CallReplied(reply),
+            }) => return Ok(reply),
+            Ok(RequestStatusResponse::Rejected {
+                reject_code,
+                reject_message,
+            }) => {
+                return Err(AgentError::ReplicaError {
+                    reject_code,
+                    reject_message,
+                }
+                .into())
+            }
+            Ok(RequestStatusResponse::Done) => {
+                return Err(anyhow!(
+                    "The request was answered but the reply is no longer available"
+                ))
+            }
+            Ok(_) => {
+                backoff = RETRY_BACKOFF_INITIAL;
+                failures = 0;
+            }
+            Err(err) if failures < env.retries && is_transient(&err) => {
+                eprintln!(
+                    "Polling the request status failed, retrying in {}ms: {}",
+                    backoff.as_millis(),
+                    err
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                failures += 1;
+            }
+            Err(err) => return Err(err.into()),
+        }
+        waiter.async_wait().await.map_err(|_| {
+            anyhow!(
+                "Timed out waiting for a reply, the request may still be executed. Request id: 0x{}",
+                String::from(*request_id)
+            )
+        })?;
+    }
+}
+
+/// Submits an update call, prints its request id and waits for the reply.
+/// The submission itself is never retried, as it may have been executed.
+pub async fn submit_and_wait(
+    env: &Env,
+    canister_id: &Principal,
+    method_name: &str,
+    arg: Vec<u8>,
+) -> NnsCliResult<Vec<u8>> {
+    let request_id = env
+        .agent
+        .update(canister_id, method_name)
+        .with_arg(arg)
+        .call()
+        .await?;
+    eprintln!("Request id: 0x{}", String::from(request_id));
+    wait_for_reply(env, canister_id, &request_id).await
+}
+
+/// Makes an update call and waits for the reply. With `--dry-run` the call is
+/// printed instead and the command stops before anything is signed.
+pub async fn update(
+    env: &Env,
+    canister_id: &Principal,
+    method_name: &str,
+    arg: Vec<u8>,
+) -> NnsCliResult<Vec<u8>> {
+    if env.dry_run {
+        print_dry_run(canister_id, method_name, &arg)?;
+        return Err(EarlyExit(0).into());
+    }
+
+    submit_and_wait(env, canister_id, method_name, arg).await
+}
@@ -0,0 +1,144 @@
+use crate::lib::agent::{query, update};
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::governance::governance_canister_id;
+
+use anyhow::anyhow;
+use candid::{CandidType, Decode, Encode};
+use ic_nns_common::pb::v1::NeuronId;
+use ic_nns_governance::pb::v1::manage_neuron::configure::Operation;
+use ic_nns_governance::pb::v1::manage_neuron::{Command, Configure};
+use ic_nns_governance::pb::v1::manage_neuron_response::{
+    Command as CommandResponse, ConfigureResponse, MakeProposalResponse,
+};
+use ic_nns_governance::pb::v1::{
+    GovernanceError, ListNeurons, ListNeuronsResponse, ManageNeuron, ManageNeuronResponse,
+    NetworkEconomics, Neuron, NeuronInfo, Proposal, ProposalInfo,
+};
+use serde::de::DeserializeOwned;
+
+const GET_FULL_NEURON_METHOD: &str = "get_full_neuron";
+const GET_NETWORK_ECONOMICS_PARAMETERS_METHOD: &str = "get_network_economics_parameters";
+const GET_NEURON_IDS_METHOD: &str = "get_neuron_ids";
+const GET_NEURON_INFO_METHOD: &str = "get_neuron_info";
+const GET_PROPOSAL_INFO_METHOD: &str = "get_proposal_info";
+const LIST_NEURONS_METHOD: &str = "list_neurons";
+const MANAGE_NEURON_METHOD: &str = "manage_neuron";
+
+const INVALID_RESPONSE: &str = "Received an invalid response.";
+
+/// Calls the governance canister. Errors reported by the canister itself are
+/// returned as `GovernanceError`, everything else fails the call.
+pub struct GovernanceClient<'a> {
+    env: &'a Env,
+}
+
+impl<'a> GovernanceClient<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        GovernanceClient { env }
+    }
+
+    async fn query<A: CandidType, R: CandidType + DeserializeOwned>(
+        &self,
+        method_name: &str,
+        arg: &A,
+    ) -> NnsCliResult<R> {
+        let result = query(
+            self.env,
+            &governance_canister_id(),
+            method_name,
+            Encode!(arg)?,
+        )
+        .await?;
+        Ok(Decode!(&result, R)?)
+    }
+
+    pub async fn get_full_neuron(&self, id: u64) -> NnsCliResult<Result<Neuron, GovernanceError>> {
+        self.query(GET_FULL_NEURON_METHOD, &id).await
+    }
+
+    pub async fn get_neuron_info(
+        &self,
+        id: u64,
+    ) -> NnsCliResult<Result<NeuronInfo, GovernanceError>> {
+        self.query(GET_NEURON_INFO_METHOD, &id).await
+    }
+
+    /// The ids of the neurons controlled by the caller.
+    pub async fn get_neuron_ids(&self) -> NnsCliResult<Vec<u64>> {
+        self.query(GET_NEURON_IDS_METHOD, &()).await
+    }
+
+    pub async fn list_neurons(
+        &self,
+        neuron_ids: Vec<u64>,
+        include_neurons_readable_by_caller: bool,
+    ) -> NnsCliResult<ListNeuronsResponse> {
+        let request = ListNeurons {
+            neuron_ids,
+            include_neurons_readable_by_caller,
+        };
+        self.query(LIST_NEURONS_METHOD, &request).await
+    }
+
+    pub async fn get_proposal_info(&self, id: u64) -> NnsCliResult<Option<ProposalInfo>> {
+        self.query(GET_PROPOSAL_INFO_METHOD, &id).await
+    }
+
+    pub async fn get_network_economics_parameters(&self) -> NnsCliResult<NetworkEconomics> {
+        self.query(GET_NETWORK_ECONOMICS_PARAMETERS_METHOD, &())
+            .await
+    }
+
+    /// Sends a manage_neuron command, honoring `--dry-run`.
+    async fn manage_neuron(
+        &self,
+        id: u64,
+        command: Command,
+    ) -> NnsCliResult<Result<CommandResponse, GovernanceError>> {
+        let manage_neuron = ManageNeuron {
+            id: Some(NeuronId { id }),
+            command: Some(command),
+        };
+        let result = update(
+            self.env,
+            &governance_canister_id(),
+            MANAGE_NEURON_METHOD,
+            Encode!(&manage_neuron)?,
+        )
+        .await?;
+        match Decode!(&result, ManageNeuronResponse)?.command {
+            Some(CommandResponse::Error(gov_err)) => Ok(Err(gov_err)),
+            Some(response) => Ok(Ok(response)),
+            None => Err(anyhow!(INVALID_RESPONSE)),
+        }
+    }
+
+    pub async fn configure(
+        &self,
+        id: u64,
+        operation: Operation,
+    ) -> NnsCliResult<Result<ConfigureResponse, GovernanceError>> {
+        let command = Command::Configure(Configure {
+            operation: Some(operation),
+        });
+        match self.manage_neuron(id, command).await? {
+            Ok(CommandResponse::Configure(response)) => Ok(Ok(response)),
+            Ok(_) => Err(anyhow!(INVALID_RESPONSE)),
+            Err(gov_err) => Ok(Err(gov_err)),
+        }
+    }
+
+    pub async fn make_proposal(
+        &self,
+        id: u64,
+        proposal: Proposal,
+    ) -> NnsCliResult<Result<MakeProposalResponse, GovernanceError>> {
+        let command = Command::MakeProposal(Box::new(proposal));
+        match self.manage_neuron(id, command).await? {
+            Ok(CommandResponse::MakeProposal(response)) => Ok(Ok(response)),
+            Ok(_) => Err(anyhow!(INVALID_RESPONSE)),
+            Err(gov_err) => Ok(Err(gov_err)),
+        }
+    }
+}
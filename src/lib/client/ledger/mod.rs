@@ -0,0 +1,219 @@
+use crate::lib::agent::{print_dry_run, query, submit_and_wait, update};
+use crate::lib::env::Env;
+use crate::lib::error::{EarlyExit, NnsCliResult};
+use crate::lib::nns_types::governance::ledger_canister_id;
+
+use anyhow::{anyhow, bail};
+use candid::{CandidType, Decode, Encode};
+use ic_types::Principal;
+use ledger_canister::{
+    AccountBalanceArgs, AccountIdentifier, Block, BlockArg, BlockHeight, BlockRes, ICPTs, Memo,
+    NotifyCanisterArgs, SendArgs, TimeStamp, TipOfChainRequest, TipOfChainRes, Transfer,
+};
+use serde::de::DeserializeOwned;
+
+const ACCOUNT_BALANCE_METHOD: &str = "account_balance_dfx";
+const ARCHIVE_BLOCK_METHOD: &str = "get_block_dfx";
+const BLOCK_METHOD: &str = "block_dfx";
+const NOTIFY_METHOD: &str = "notify_dfx";
+const SEND_METHOD: &str = "send_dfx";
+const TIP_OF_CHAIN_METHOD: &str = "tip_of_chain_dfx";
+
+// How far ahead of the ledger's clock a transaction's `created_at_time` may be.
+const PERMITTED_DRIFT_NANOS: u64 = 5 * 60 * 1_000_000_000;
+
+// The most blocks `find_transaction` reads once it located `created_at_time`.
+const FIND_TRANSACTION_BLOCKS_MAX: u64 = 1_000;
+
+/// Calls the ledger canister and, for archived blocks, its archive canisters.
+pub struct LedgerClient<'a> {
+    env: &'a Env,
+}
+
+impl<'a> LedgerClient<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        LedgerClient { env }
+    }
+
+    /// With `certified` the balance is fetched with an update call, so that
+    /// the result is certified by the subnet. The balance is read only, so it
+    /// is fetched even with `--dry-run`.
+    pub async fn account_balance(
+        &self,
+        account: AccountIdentifier,
+        certified: bool,
+    ) -> NnsCliResult<ICPTs> {
+        let arg = Encode!(&AccountBalanceArgs { account })?;
+        let result = if certified {
+            submit_and_wait(self.env, &ledger_canister_id(), ACCOUNT_BALANCE_METHOD, arg).await?
+        } else {
+            query(self.env, &ledger_canister_id(), ACCOUNT_BALANCE_METHOD, arg).await?
+        };
+        Ok(Decode!(&result, ICPTs)?)
+    }
+
+    pub async fn tip_of_chain(&self) -> NnsCliResult<TipOfChainRes> {
+        let arg = Encode!(&TipOfChainRequest {})?;
+        let result = query(self.env, &ledger_canister_id(), TIP_OF_CHAIN_METHOD, arg).await?;
+        Ok(Decode!(&result, TipOfChainRes)?)
+    }
+
+    async fn query_block(
+        &self,
+        canister_id: &Principal,
+        method_name: &str,
+        height: BlockHeight,
+    ) -> NnsCliResult<BlockRes> {
+        let arg = Encode!(&BlockArg(height))?;
+        let result = query(self.env, canister_id, method_name, arg).await?;
+        Ok(Decode!(&result, BlockRes)?)
+    }
+
+    /// Fetches the block at `height`, following the ledger to the archive
+    /// canister if the block has been archived. Returns `None` if the block
+    /// does not exist yet.
+    pub async fn block(&self, height: BlockHeight) -> NnsCliResult<Option<Block>> {
+        let encoded_block = match self
+            .query_block(&ledger_canister_id(), BLOCK_METHOD, height)
+            .await?
+            .0
+        {
+            None => return Ok(None),
+            Some(Ok(encoded_block)) => encoded_block,
+            Some(Err(archive_canister_id)) => {
+                let archive_canister_id = Principal::from_slice(archive_canister_id.as_ref());
+                match self
+                    .query_block(&archive_canister_id, ARCHIVE_BLOCK_METHOD, height)
+                    .await?
+                    .0
+                {
+                    Some(Ok(encoded_block)) => encoded_block,
+                    _ => {
+                        return Err(anyhow!(
+                            "Block {} is not available from archive canister {}",
+                            height,
+                            archive_canister_id
+                        ))
+                    }
+                }
+            }
+        };
+        let block = encoded_block
+            .decode()
+            .map_err(|err| anyhow!("Could not decode block {}: {}", height, err))?;
+        Ok(Some(block))
+    }
+
+    /// Returns the height of the first block with a timestamp at or after
+    /// `timestamp_nanos`, or `tip + 1` if there is none. Blocks are ordered by
+    /// time, so this is a binary search.
+    async fn first_block_at_or_after(
+        &self,
+        timestamp_nanos: u64,
+        tip: BlockHeight,
+    ) -> NnsCliResult<BlockHeight> {
+        let (mut low, mut high) = (0, tip + 1);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let block = self
+                .block(mid)
+                .await?
+                .ok_or_else(|| anyhow!("Block {} does not exist", mid))?;
+            if block.timestamp.timestamp_nanos < timestamp_nanos {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Searches for the block recording `transfer` with `memo` and
+    /// `created_at_time`. This finds the original of a
+    /// transaction the ledger rejected as a duplicate. At most
+    /// `FIND_TRANSACTION_BLOCKS_MAX` blocks are read after `created_at_time`.
+    pub async fn find_transaction(
+        &self,
+        memo: Memo,
+        transfer: &Transfer,
+        created_at_time: TimeStamp,
+    ) -> NnsCliResult<Option<BlockHeight>> {
+        let earliest_nanos = created_at_time
+            .timestamp_nanos
+            .saturating_sub(PERMITTED_DRIFT_NANOS);
+        let tip = self.tip_of_chain().await?.tip_index;
+        let start = self.first_block_at_or_after(earliest_nanos, tip).await?;
+        let end = start
+            .saturating_add(FIND_TRANSACTION_BLOCKS_MAX)
+            .min(tip + 1);
+        for height in start..end {
+            if let Some(block) = self.block(height).await? {
+                // The ledger deduplicates on the creation time too, so it is
+                // what tells the original apart from identical earlier sends.
+                let transaction = &block.transaction;
+                if transaction.created_at_time == created_at_time
+                    && transaction.memo == memo
+                    && &transaction.transfer == transfer
+                {
+                    return Ok(Some(height));
+                }
+            }
+        }
+        if end <= tip {
+            bail!(
+                "The original transaction is not in the {} blocks from height {}, look for it with `icx-nns ledger history --from-block {}`",
+                FIND_TRANSACTION_BLOCKS_MAX,
+                start,
+                start
+            );
+        }
+        Ok(None)
+    }
+
+    /// With `--dry-run`, prints both the send and the notify call of a
+    /// transfer to a canister and stops. The block height in `notify_args` is
+    /// a placeholder, as it is only known once the transfer is sent.
+    pub fn dry_run_send_and_notify(
+        &self,
+        send_args: &SendArgs,
+        notify_args: &NotifyCanisterArgs,
+    ) -> NnsCliResult {
+        if !self.env.dry_run {
+            return Ok(());
+        }
+        print_dry_run(&ledger_canister_id(), SEND_METHOD, &Encode!(send_args)?)?;
+        println!();
+        print_dry_run(&ledger_canister_id(), NOTIFY_METHOD, &Encode!(notify_args)?)?;
+        println!("The block_height is a placeholder for the height of the transfer.");
+        Err(EarlyExit(0).into())
+    }
+
+    /// Sends ICP, honoring `--dry-run`. Returns the height of the block
+    /// recording the transfer.
+    pub async fn send(&self, args: SendArgs) -> NnsCliResult<BlockHeight> {
+        let result = update(
+            self.env,
+            &ledger_canister_id(),
+            SEND_METHOD,
+            Encode!(&args)?,
+        )
+        .await?;
+        Ok(Decode!(&result, BlockHeight)?)
+    }
+
+    /// Notifies the receiving canister of a transfer, honoring `--dry-run`.
+    /// Returns the reply of the notified canister.
+    pub async fn notify<R: CandidType + DeserializeOwned>(
+        &self,
+        args: NotifyCanisterArgs,
+    ) -> NnsCliResult<R> {
+        let result = update(
+            self.env,
+            &ledger_canister_id(),
+            NOTIFY_METHOD,
+            Encode!(&args)?,
+        )
+        .await?;
+        Ok(Decode!(&result, R)?)
+    }
+}
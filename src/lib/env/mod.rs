@@ -1,7 +1,12 @@
 use ic_agent::Agent;
 use ic_types::Principal;
+use std::time::Duration;
 
 pub struct Env {
     pub agent: Agent,
     pub sender: Principal,
+    pub dry_run: bool,
+    pub yes: bool,
+    pub timeout: Duration,
+    pub retries: u32,
 }
@@ -1,2 +1,15 @@
 // The type to represent NNS CLI results.
 pub type NnsCliResult<T = ()> = anyhow::Result<T>;
+
+/// Stops a command early without reporting an error, e.g. after printing a
+/// dry run. The process exits with the given code.
+#[derive(Debug)]
+pub struct EarlyExit(pub i32);
+
+impl std::fmt::Display for EarlyExit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Exited early with code {}", self.0)
+    }
+}
+
+impl std::error::Error for EarlyExit {}
@@ -1,5 +1,7 @@
 pub mod agent;
+pub mod client;
 pub mod env;
 pub mod error;
 pub mod identity;
 pub mod nns_types;
+pub mod prompt;
@@ -1,4 +1,29 @@
+use crate::lib::error::NnsCliResult;
+use crate::lib::nns_types::payloads::{
+    AddNodeOperatorPayload, AddNodesToSubnetPayload, ChangeNnsCanisterProposalPayload,
+    CreateSubnetPayload, RemoveNodesFromSubnetPayload, SetAuthorizedSubnetworkListArgs,
+    UpdateIcpXdrConversionRatePayload, UpdateSubnetPayload,
+};
+use crate::lib::nns_types::utils::ONE_YEAR_SECONDS;
+
+use anyhow::anyhow;
+use candid::{CandidType, Decode, IDLArgs};
+use ic_nns_governance::pb::v1::neuron::DissolveState;
+use ic_nns_governance::pb::v1::proposal::Action;
+use ic_nns_governance::pb::v1::{Neuron, NnsFunction, Tally};
 use ic_types::Principal;
+use ledger_canister::ICPTs;
+use openssl::sha::sha256;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const MIN_DISSOLVE_DELAY_FOR_VOTE_ELIGIBILITY_SECONDS: u64 = ONE_YEAR_SECONDS / 2;
+pub const MAX_DISSOLVE_DELAY_SECONDS: u64 = 8 * ONE_YEAR_SECONDS;
+pub const MAX_NEURON_AGE_FOR_AGE_BONUS: u64 = 4 * ONE_YEAR_SECONDS;
+
+// Payloads larger than this, i.e. wasm modules, are shown by their hash only.
+const PAYLOAD_DISPLAY_BYTES_MAX: usize = 10_000;
 
 pub fn ledger_canister_id() -> Principal {
     Principal::from_slice(ic_nns_constants::LEDGER_CANISTER_ID.as_ref())
@@ -7,3 +32,197 @@ pub fn ledger_canister_id() -> Principal {
 pub fn governance_canister_id() -> Principal {
     Principal::from_slice(ic_nns_constants::GOVERNANCE_CANISTER_ID.as_ref())
 }
+
+pub fn nns_function_validator(nns_function: &str) -> Result<(), String> {
+    nns_function_from_str(nns_function)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Parses an `NnsFunction` from its name, e.g. `SetAuthorizedSubnetworks`, or its number.
+pub fn nns_function_from_str(s: &str) -> NnsCliResult<NnsFunction> {
+    let nns_function = match s.parse::<i32>() {
+        Ok(v) => NnsFunction::from_i32(v),
+        // The enum values are contiguous, so stop at the first unknown one.
+        Err(_) => (1..)
+            .map(NnsFunction::from_i32)
+            .take_while(Option::is_some)
+            .flatten()
+            .find(|v| format!("{:?}", v) == s),
+    };
+    match nns_function {
+        Some(NnsFunction::Unspecified) | None => Err(anyhow!("Unknown NNS function {}", s)),
+        Some(v) => Ok(v),
+    }
+}
+
+/// Computes a neuron's voting power the way the governance canister does: the
+/// dissolve delay adds up to 100% at 8 years and the age adds up to 25% at 4
+/// years. Neurons with a dissolve delay below 6 months cannot vote.
+pub fn voting_power(stake_e8s: u64, dissolve_delay_seconds: u64, age_seconds: u64) -> u64 {
+    if dissolve_delay_seconds < MIN_DISSOLVE_DELAY_FOR_VOTE_ELIGIBILITY_SECONDS {
+        return 0;
+    }
+    let stake = stake_e8s as u128;
+    let d = dissolve_delay_seconds.min(MAX_DISSOLVE_DELAY_SECONDS) as u128;
+    let d_stake = stake + (stake * d) / MAX_DISSOLVE_DELAY_SECONDS as u128;
+    let a = age_seconds.min(MAX_NEURON_AGE_FOR_AGE_BONUS) as u128;
+    let ad_stake = d_stake + (d_stake * a) / (4 * MAX_NEURON_AGE_FOR_AGE_BONUS as u128);
+    ad_stake as u64
+}
+
+/// The stake of a neuron, net of the fees for its rejected proposals.
+pub fn neuron_stake_e8s(neuron: &Neuron) -> u64 {
+    neuron
+        .cached_neuron_stake_e8s
+        .saturating_sub(neuron.neuron_fees_e8s)
+}
+
+/// The dissolve delay left, which for a dissolving neuron is the time until it
+/// is dissolved.
+pub fn dissolve_delay_seconds(neuron: &Neuron) -> NnsCliResult<u64> {
+    match neuron.dissolve_state {
+        Some(DissolveState::DissolveDelaySeconds(seconds)) => Ok(seconds),
+        Some(DissolveState::WhenDissolvedTimestampSeconds(timestamp)) => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            Ok(timestamp.saturating_sub(now))
+        }
+        None => Ok(0),
+    }
+}
+
+/// Returns the name of a protobuf enum value, or the raw value if it is unknown.
+pub fn enum_name<T: Debug>(value: Option<T>, raw: i32) -> String {
+    value.map_or_else(|| raw.to_string(), |v| format!("{:?}", v))
+}
+
+/// Formats the votes of a tally in ICP and as percentages of the total.
+pub fn format_tally(tally: &Tally) -> String {
+    let percent = |votes: u64| {
+        if tally.total == 0 {
+            0.0
+        } else {
+            votes as f64 * 100.0 / tally.total as f64
+        }
+    };
+    format!(
+        "yes {} ({:.2}%), no {} ({:.2}%), total {}",
+        ICPTs::from_e8s(tally.yes),
+        percent(tally.yes),
+        ICPTs::from_e8s(tally.no),
+        percent(tally.no),
+        ICPTs::from_e8s(tally.total)
+    )
+}
+
+fn format_hash(bytes: &[u8]) -> String {
+    format!(
+        "{} bytes with SHA-256 {}",
+        bytes.len(),
+        hex::encode(sha256(bytes))
+    )
+}
+
+fn decode_payload<T: CandidType + DeserializeOwned + Debug>(payload: &[u8]) -> Option<String> {
+    Decode!(payload, T).ok().map(|v| format!("{:#?}", v))
+}
+
+/// Decodes the payload of the NNS functions this tool knows the payload type of,
+/// and falls back to untyped Candid otherwise.
+fn format_payload(nns_function: Option<NnsFunction>, payload: &[u8]) -> String {
+    let decoded = match nns_function {
+        Some(NnsFunction::SetAuthorizedSubnetworks) => {
+            decode_payload::<SetAuthorizedSubnetworkListArgs>(payload)
+        }
+        Some(NnsFunction::NnsCanisterUpgrade) => Decode!(payload, ChangeNnsCanisterProposalPayload)
+            .ok()
+            .map(|v| {
+                format!(
+                    "canister_id: {}\nmode: {:?}\nwasm_module: {}\narg: {}",
+                    v.canister_id,
+                    v.mode,
+                    format_hash(&v.wasm_module),
+                    IDLArgs::from_bytes(&v.arg)
+                        .map_or_else(|_| hex::encode(&v.arg), |args| args.to_string())
+                )
+            }),
+        Some(NnsFunction::AssignNoid) => decode_payload::<AddNodeOperatorPayload>(payload),
+        Some(NnsFunction::CreateSubnet) => decode_payload::<CreateSubnetPayload>(payload),
+        Some(NnsFunction::AddNodeToSubnet) => decode_payload::<AddNodesToSubnetPayload>(payload),
+        Some(NnsFunction::RemoveNodesFromSubnet) => {
+            decode_payload::<RemoveNodesFromSubnetPayload>(payload)
+        }
+        Some(NnsFunction::UpdateConfigOfSubnet) => decode_payload::<UpdateSubnetPayload>(payload),
+        Some(NnsFunction::IcpXdrConversionRate) => {
+            decode_payload::<UpdateIcpXdrConversionRatePayload>(payload)
+        }
+        _ => None,
+    };
+    decoded.unwrap_or_else(|| match IDLArgs::from_bytes(payload) {
+        Ok(args) if payload.len() <= PAYLOAD_DISPLAY_BYTES_MAX => args.to_string(),
+        _ => format_hash(payload),
+    })
+}
+
+/// Renders a proposal action, decoding the Candid payload of NNS functions.
+pub fn format_action(action: &Action) -> String {
+    match action {
+        Action::ExecuteNnsFunction(execute_nns_function) => {
+            let nns_function = NnsFunction::from_i32(execute_nns_function.nns_function);
+            format!(
+                "ExecuteNnsFunction {}\n{}",
+                nns_function.map_or_else(
+                    || execute_nns_function.nns_function.to_string(),
+                    |v| format!("{:?}", v)
+                ),
+                format_payload(nns_function, &execute_nns_function.payload)
+            )
+        }
+        _ => format!("{:#?}", action),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_ICP_E8S: u64 = 100_000_000;
+
+    #[test]
+    fn voting_power_is_zero_below_minimum_dissolve_delay() {
+        let delay = MIN_DISSOLVE_DELAY_FOR_VOTE_ELIGIBILITY_SECONDS - 1;
+        assert_eq!(voting_power(ONE_ICP_E8S, delay, 0), 0);
+    }
+
+    #[test]
+    fn voting_power_at_minimum_dissolve_delay() {
+        let delay = MIN_DISSOLVE_DELAY_FOR_VOTE_ELIGIBILITY_SECONDS;
+        // Half a year out of eight adds 1/16 of the stake.
+        assert_eq!(voting_power(ONE_ICP_E8S, delay, 0), 106_250_000);
+    }
+
+    #[test]
+    fn voting_power_with_maximum_bonuses() {
+        // An 8 year delay doubles the stake and 4 years of age adds another 25%.
+        assert_eq!(
+            voting_power(
+                ONE_ICP_E8S,
+                MAX_DISSOLVE_DELAY_SECONDS,
+                MAX_NEURON_AGE_FOR_AGE_BONUS
+            ),
+            250_000_000
+        );
+    }
+
+    #[test]
+    fn voting_power_caps_delay_and_age() {
+        assert_eq!(
+            voting_power(
+                ONE_ICP_E8S,
+                2 * MAX_DISSOLVE_DELAY_SECONDS,
+                2 * MAX_NEURON_AGE_FOR_AGE_BONUS
+            ),
+            250_000_000
+        );
+    }
+}
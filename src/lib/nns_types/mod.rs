@@ -1,2 +1,3 @@
 pub mod governance;
+pub mod payloads;
 pub mod utils;
@@ -0,0 +1,134 @@
+// Candid mirrors of the payloads of NNS functions, as defined by the
+// canisters that execute them.
+
+use anyhow::anyhow;
+use candid::{CandidType, Nat};
+use ic_types::Principal;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// The payload of `SetAuthorizedSubnetworks`, executed by the cycles minting canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct SetAuthorizedSubnetworkListArgs {
+    pub who: Option<Principal>,
+    pub subnets: Vec<Principal>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CanisterInstallMode {
+    #[serde(rename = "install")]
+    Install,
+    #[serde(rename = "reinstall")]
+    Reinstall,
+    #[serde(rename = "upgrade")]
+    Upgrade,
+}
+
+/// The payload of `NnsCanisterUpgrade`, executed by the root canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ChangeNnsCanisterProposalPayload {
+    pub stop_before_installing: bool,
+    pub mode: CanisterInstallMode,
+    pub canister_id: Principal,
+    pub wasm_module: Vec<u8>,
+    pub arg: Vec<u8>,
+    pub compute_allocation: Option<Nat>,
+    pub memory_allocation: Option<Nat>,
+    pub query_allocation: Option<Nat>,
+}
+
+/// The payload of `AssignNoid`, executed by the registry canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AddNodeOperatorPayload {
+    pub node_operator_principal_id: Option<Principal>,
+    pub node_allowance: u64,
+    pub node_provider_principal_id: Option<Principal>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Copy, Debug)]
+pub enum SubnetType {
+    #[serde(rename = "application")]
+    Application,
+    #[serde(rename = "system")]
+    System,
+    #[serde(rename = "verified_application")]
+    VerifiedApplication,
+}
+
+impl FromStr for SubnetType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "application" => Ok(SubnetType::Application),
+            "system" => Ok(SubnetType::System),
+            "verified-application" => Ok(SubnetType::VerifiedApplication),
+            _ => Err(anyhow!("Unknown subnet type {}", s)),
+        }
+    }
+}
+
+/// The payload of `CreateSubnet`, executed by the registry canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CreateSubnetPayload {
+    pub node_ids: Vec<Principal>,
+    pub subnet_id_override: Option<Principal>,
+    pub ingress_bytes_per_block_soft_cap: u64,
+    pub max_ingress_bytes_per_message: u64,
+    pub max_ingress_messages_per_block: u64,
+    pub max_block_payload_size: u64,
+    pub unit_delay_millis: u64,
+    pub initial_notary_delay_millis: u64,
+    pub replica_version_id: String,
+    pub dkg_interval_length: u64,
+    pub dkg_dealings_per_block: u64,
+    pub gossip_max_artifact_streams_per_peer: u32,
+    pub gossip_max_chunk_wait_ms: u32,
+    pub gossip_max_duplicity: u32,
+    pub gossip_max_chunk_size: u32,
+    pub gossip_receive_check_cache_size: u32,
+    pub gossip_pfn_evaluation_period_ms: u32,
+    pub gossip_registry_poll_period_ms: u32,
+    pub gossip_retransmission_request_ms: u32,
+    pub start_as_nns: bool,
+    pub subnet_type: SubnetType,
+    pub is_halted: bool,
+}
+
+/// The payload of `AddNodeToSubnet`, executed by the registry canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AddNodesToSubnetPayload {
+    pub subnet_id: Principal,
+    pub node_ids: Vec<Principal>,
+}
+
+/// The payload of `RemoveNodesFromSubnet`, executed by the registry canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct RemoveNodesFromSubnetPayload {
+    pub node_ids: Vec<Principal>,
+}
+
+/// The payload of `UpdateConfigOfSubnet`, executed by the registry canister.
+/// Fields left as `None` keep their current value.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct UpdateSubnetPayload {
+    pub subnet_id: Principal,
+    pub ingress_bytes_per_block_soft_cap: Option<u64>,
+    pub max_ingress_bytes_per_message: Option<u64>,
+    pub max_block_payload_size: Option<u64>,
+    pub unit_delay_millis: Option<u64>,
+    pub initial_notary_delay_millis: Option<u64>,
+    pub dkg_interval_length: Option<u64>,
+    pub dkg_dealings_per_block: Option<u64>,
+    pub start_as_nns: Option<bool>,
+    pub subnet_type: Option<SubnetType>,
+    pub is_halted: Option<bool>,
+}
+
+/// The payload of `IcpXdrConversionRate`, executed by the cycles minting canister.
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct UpdateIcpXdrConversionRatePayload {
+    pub data_source: String,
+    pub timestamp_seconds: u64,
+    pub xdr_permyriad_per_icp: u64,
+}
@@ -1,18 +1,33 @@
 use crate::lib::error::NnsCliResult;
 
 use anyhow::anyhow;
+use chrono::NaiveDateTime;
 use ic_base_types::PrincipalId;
-use ledger_canister::{ICPTs, Memo, Subaccount, DECIMAL_PLACES};
+use ic_types::Principal;
+use ledger_canister::{AccountIdentifier, ICPTs, Memo, Subaccount, DECIMAL_PLACES};
 use openssl::sha::Sha256;
 use rust_decimal::Decimal;
 use std::convert::TryFrom;
 use std::str::FromStr;
+use std::time::Duration;
 
 pub fn icpts_amount_validator(icpts: &str) -> Result<(), String> {
     let err_message = format!("Could not convert {} to ICP type", icpts);
     icpts_from_str(icpts).map(|_| ()).map_err(|_| err_message)
 }
 
+pub fn duration_validator(duration: &str) -> Result<(), String> {
+    duration_from_str(duration)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Parses a human readable duration, i.e. `30s` or `180d`.
+pub fn duration_from_str(duration: &str) -> NnsCliResult<Duration> {
+    humanize_rs::duration::parse(duration)
+        .map_err(|err| anyhow!("Could not parse duration {}: {:?}", duration, err))
+}
+
 pub fn get_governance_subaccount(memo: Memo, principal: PrincipalId) -> Subaccount {
     Subaccount::try_from(
         &{
@@ -27,6 +42,40 @@ pub fn get_governance_subaccount(memo: Memo, principal: PrincipalId) -> Subaccou
     .expect("Couldn't build subaccount from hash.")
 }
 
+pub fn subaccount_validator(subaccount: &str) -> Result<(), String> {
+    subaccount_from_str(subaccount)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Parses a subaccount from 64 hex characters, or from an index which is
+/// stored big-endian in the last 8 bytes of the subaccount.
+pub fn subaccount_from_str(s: &str) -> NnsCliResult<Subaccount> {
+    let mut subaccount = [0_u8; 32];
+    if s.len() == 64 {
+        hex::decode_to_slice(s, &mut subaccount)
+            .map_err(|err| anyhow!("Could not decode subaccount {}: {}", s, err))?;
+    } else {
+        let index = s
+            .parse::<u64>()
+            .map_err(|_| anyhow!("Subaccount {} is neither 64 hex characters nor an index", s))?;
+        subaccount[24..].copy_from_slice(&index.to_be_bytes());
+    }
+    Ok(Subaccount(subaccount))
+}
+
+/// Computes the AccountIdentifier of a principal's subaccount, given as
+/// accepted by `subaccount_from_str`.
+pub fn account_from_principal(
+    principal: &str,
+    subaccount: Option<&str>,
+) -> NnsCliResult<AccountIdentifier> {
+    let principal = Principal::from_text(principal)?;
+    let principal = PrincipalId::try_from(principal.as_slice()).map_err(|err| anyhow!(err))?;
+    let subaccount = subaccount.map(subaccount_from_str).transpose()?;
+    Ok(AccountIdentifier::new(principal, subaccount))
+}
+
 pub fn icpts_from_str(s: &str) -> NnsCliResult<ICPTs> {
     match Decimal::from_str(s) {
         Ok(amount) => {
@@ -78,3 +127,78 @@ pub fn get_icpts_from_args(
             .map_err(|err| anyhow!("Could not add ICPs and e8s: {}", err))?)
     }
 }
+
+/// The length of a year as used by the governance canister.
+pub const ONE_YEAR_SECONDS: u64 = (4 * 365 + 1) * 24 * 3600 / 4;
+
+/// Formats a number of seconds as e.g. `1y 182d 15h`.
+pub fn format_duration(seconds: u64) -> String {
+    let units = [
+        ("y", ONE_YEAR_SECONDS),
+        ("d", 24 * 3600),
+        ("h", 3600),
+        ("m", 60),
+        ("s", 1),
+    ];
+    let mut remaining = seconds;
+    let mut parts = Vec::new();
+    for (suffix, unit) in units.iter() {
+        if remaining >= *unit {
+            parts.push(format!("{}{}", remaining / unit, suffix));
+            remaining %= unit;
+        }
+    }
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Formats seconds since the Unix epoch as a UTC date and time.
+pub fn format_timestamp(seconds: u64) -> String {
+    NaiveDateTime::from_timestamp(seconds as i64, 0)
+        .format("%Y-%m-%d %H:%M:%S UTC")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subaccount_from_index() {
+        let Subaccount(bytes) = subaccount_from_str("1").unwrap();
+        let mut expected = [0_u8; 32];
+        expected[31] = 0x01;
+        assert_eq!(bytes, expected);
+
+        let Subaccount(bytes) = subaccount_from_str("256").unwrap();
+        let mut expected = [0_u8; 32];
+        expected[30] = 0x01;
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn subaccount_from_hex() {
+        let hex = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        let Subaccount(bytes) = subaccount_from_str(hex).unwrap();
+        assert_eq!(hex::encode(bytes), hex);
+    }
+
+    #[test]
+    fn subaccount_rejects_invalid_input() {
+        assert!(subaccount_from_str("-1").is_err());
+        assert!(subaccount_from_str("0x01").is_err());
+        assert!(subaccount_from_str(&"g".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn account_of_anonymous_principal() {
+        let account_id = account_from_principal("2vxsx-fae", None).unwrap();
+        assert_eq!(
+            account_id.to_string(),
+            "1c7a48ba6a562aa9eaa2481a9049cdf0433b9738c992d698c31d8abf89cadc79"
+        );
+    }
+}
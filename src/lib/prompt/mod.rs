@@ -0,0 +1,27 @@
+use crate::lib::env::Env;
+use crate::lib::error::NnsCliResult;
+
+use anyhow::bail;
+use std::io::Write;
+
+/// Prints a summary of what a command is about to do and asks the user to
+/// confirm it. The question is skipped with `--yes` or `--dry-run`, and the
+/// command is refused when stdin is not a terminal.
+pub fn confirm(env: &Env, summary: &str) -> NnsCliResult {
+    eprintln!("{}", summary);
+    if env.yes || env.dry_run {
+        return Ok(());
+    }
+    if !atty::is(atty::Stream::Stdin) {
+        bail!("Stdin is not a terminal, pass --yes to proceed without confirmation.");
+    }
+
+    eprint!("Continue? [y/N] ");
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(()),
+        _ => bail!("Aborted."),
+    }
+}
@@ -1,6 +1,8 @@
 use crate::lib::agent::construct_agent;
 use crate::lib::env::Env;
+use crate::lib::error::EarlyExit;
 use crate::lib::identity::create_identity;
+use crate::lib::nns_types::utils::{duration_from_str, duration_validator};
 use clap::{crate_version, AppSettings, Clap};
 
 use anyhow::anyhow;
@@ -25,6 +27,22 @@ pub struct Opts {
     /// A flag to control whether or not to use the HSM backed identity
     #[clap(long)]
     use_hsm: bool,
+
+    /// Print the update calls that would be made instead of sending them
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Skip confirmation prompts
+    #[clap(long)]
+    yes: bool,
+
+    /// How long to wait for the reply to an update call, i.e. 90s
+    #[clap(long, default_value = "5m", validator(duration_validator))]
+    timeout: String,
+
+    /// How many times to retry a call that failed with a transient error
+    #[clap(long, default_value = "3")]
+    retries: u32,
 }
 
 fn main() {
@@ -37,21 +55,36 @@ fn main() {
             (format!("http://{}", v), true)
         });
     let use_hsm = opts.use_hsm;
+    let dry_run = opts.dry_run;
+    let yes = opts.yes;
+    let timeout = opts.timeout;
+    let retries = opts.retries;
 
     let runtime = Runtime::new().expect("Unable to create a runtime");
 
     let result = runtime.block_on(async {
+        let timeout = duration_from_str(&timeout)?;
         let identity = create_identity(use_hsm)?;
         let sender = identity.sender().map_err(|err| anyhow!("{}", err))?;
 
         let agent = construct_agent(identity, network.clone(), fetch_root_key).await?;
 
-        let env = Env { agent, sender };
+        let env = Env {
+            agent,
+            sender,
+            dry_run,
+            yes,
+            timeout,
+            retries,
+        };
 
         commands::exec(command, env).await
     });
 
     if let Err(err) = result {
+        if let Some(EarlyExit(code)) = err.downcast_ref::<EarlyExit>() {
+            std::process::exit(*code);
+        }
         eprintln!("{}", err);
         std::process::exit(255);
     }